@@ -0,0 +1,43 @@
+//! 数据库管理相关的抽象语法树定义
+use crate::{Expr, Literal, SchemaObject};
+
+/// PRAGMA 语句
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pragma {
+    pub name: SchemaObject,
+    pub value: Option<PragmaValue>, // `= value` 与 `(value)` 两种写法
+}
+
+/// PRAGMA 的取值
+#[derive(Clone, Debug, PartialEq)]
+pub enum PragmaValue {
+    Number(Literal), // 有符号数字
+    Name(String),    // 名称，可以是关键字
+    String(String),  // 字符串字面量
+}
+
+/// 附加数据库语句
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attach {
+    pub expr: Expr, // 数据库文件名
+    pub schema_name: String,
+}
+
+/// 分离数据库语句
+#[derive(Clone, Debug, PartialEq)]
+pub struct Detach(pub String);
+
+/// 整理数据库语句
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vacuum {
+    pub schema_name: Option<String>,
+    pub into: Option<Expr>, // 目标文件名
+}
+
+/// 收集统计信息语句
+#[derive(Clone, Debug, PartialEq)]
+pub struct Analyze(pub Option<SchemaObject>);
+
+/// 重建索引语句，名称可以是排序规则、表或索引
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reindex(pub Option<SchemaObject>);
@@ -1,6 +1,6 @@
 //! 数据定义语言（Data Definition Language, DDL）相关的抽象语法树定义
 
-use crate::{Dml, Expr, Literal, SchemaObject, Select};
+use crate::{ConflictResolution, Dml, Expr, Literal, SchemaObject, Select};
 
 /// 建表语句
 #[derive(Clone, Debug, PartialEq)]
@@ -11,6 +11,15 @@ pub struct CreateTable {
     pub body: CreateTableBody,
 }
 
+/// 虚拟表创建语句
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateVirtualTable {
+    pub if_not_exists: bool,
+    pub schema_table: SchemaObject,
+    pub module: String,
+    pub args: Vec<String>, // 模块参数原文
+}
+
 /// 索引创建语句
 #[derive(Clone, Debug, PartialEq)]
 pub struct CreateIndex {
@@ -120,35 +129,107 @@ pub struct ColumnConstraint {
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ColumnConstraintType {
-    PrimaryKey { asc: bool, auto_inc: bool }, // 默认升序
-    NotNull,
-    Unique,
+    PrimaryKey {
+        asc: bool, // 默认升序
+        conflict: Option<ConflictResolution>,
+        auto_inc: bool,
+    },
+    NotNull {
+        conflict: Option<ConflictResolution>,
+    },
+    Unique {
+        conflict: Option<ConflictResolution>,
+    },
     Check(Expr),
-    Default(Literal),
+    Default(DefaultValue),
+    Collate(String),
+    ForeignKey(ForeignKeyClause),
+    Generated {
+        expr: Expr,
+        stored: bool,
+    }, // 默认 VIRTUAL
+}
+
+/// 列默认值
+#[derive(Clone, Debug, PartialEq)]
+pub enum DefaultValue {
+    Literal(Literal),      // 字面量
+    SignedNumber(Literal), // 带符号的数字，符号并入字面量
+    Expr(Expr),            // 括号中的表达式
+    CurrentTime,           // CURRENT_TIME
+    CurrentDate,           // CURRENT_DATE
+    CurrentTimestamp,      // CURRENT_TIMESTAMP
+    Ident(String),         // 标识符
 }
 
 /// 表级约束
 #[derive(Clone, Debug, PartialEq)]
 pub struct TableConstraint {
     pub name: Option<String>,
-    pub cols: Vec<IndexedColumn>,
     pub ty: TableConstraintType,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TableConstraintType {
-    PrimaryKey,
-    Unique,
+    PrimaryKey {
+        cols: Vec<IndexedColumn>,
+        conflict: Option<ConflictResolution>,
+    },
+    Unique {
+        cols: Vec<IndexedColumn>,
+        conflict: Option<ConflictResolution>,
+    },
+    Check(Expr),
+    ForeignKey {
+        columns: Vec<String>,
+        clause: ForeignKeyClause,
+    },
+}
+
+/// 外键子句
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForeignKeyClause {
+    pub foreign_table: String,
+    pub columns: Vec<String>,
+    pub on_delete: FkAction, // 默认 NoAction
+    pub on_update: FkAction, // 默认 NoAction
+    pub match_name: Option<String>,
+    pub deferrable: Option<Deferrable>,
+}
+
+/// 外键动作
+#[derive(Clone, Debug, PartialEq)]
+pub enum FkAction {
+    SetNull,
+    SetDefault,
+    Cascade,
+    Restrict,
+    NoAction,
+}
+
+/// 外键约束的延迟检查设置
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deferrable {
+    pub not: bool,                    // NOT DEFERRABLE
+    pub initially: Option<Initially>, // INITIALLY DEFERRED / IMMEDIATE
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Initially {
+    Deferred,
+    Immediate,
 }
 
 /// 被索引的列
 #[derive(Clone, Debug, PartialEq)]
 pub struct IndexedColumn {
-    pub name: String,
+    pub expr: Expr, // 列名或表达式
+    pub collation: Option<String>,
     pub asc: bool, // 默认升序
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum CreateTableBody {
     Select(Select),
     Columns {
@@ -1,8 +1,9 @@
 //! 数据操作语言（Data Manipulation Language, DML）相关的抽象语法树定义
-use crate::{ConflictResolution, Expr, IndexedColumn, SchemaObject};
+use crate::{ConflictResolution, Expr, IndexedColumn, SchemaObject, WindowDefn};
 
 // DML 语句枚举
 #[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum Dml {
     Select(Select),
     Insert(Insert),
@@ -13,6 +14,7 @@ pub enum Dml {
 /// Select 语句
 #[derive(Clone, Debug, PartialEq)]
 pub struct Select {
+    pub with_clause: Option<WithClause>,
     pub core: SelectCore,
     pub compound: Vec<(CompoundOperator, SelectCore)>,
     pub order_by: Vec<OrderingTerm>,
@@ -23,6 +25,7 @@ pub struct Select {
 /// Insert 语句
 #[derive(Clone, Debug, PartialEq)]
 pub struct Insert {
+    pub with_clause: Option<WithClause>,
     pub header: InsertHeader,
     pub schema_table: SchemaObject,
     pub alias: Option<String>,
@@ -34,6 +37,7 @@ pub struct Insert {
 /// Update 语句
 #[derive(Clone, Debug, PartialEq)]
 pub struct Update {
+    pub with_clause: Option<WithClause>,
     pub conflict: ConflictResolution,
     pub qualified_table: QualifiedTable,
     pub set_clause: Vec<SetSubClause>,
@@ -45,13 +49,31 @@ pub struct Update {
 /// Delete 语句
 #[derive(Clone, Debug, PartialEq)]
 pub struct Delete {
+    pub with_clause: Option<WithClause>,
     pub qualified_table: QualifiedTable,
     pub where_clause: Option<Expr>,
     pub return_clause: Vec<ReturnSubClause>,
 }
 
+/// WITH 子句
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithClause {
+    pub recursive: bool,
+    pub ctes: Vec<CommonTableExpr>,
+}
+
+/// 公用表表达式（CTE）
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommonTableExpr {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub materialized: Option<bool>, // None 表示未指定，Some(false) 表示 NOT MATERIALIZED
+    pub select: Select,
+}
+
 /// Select 语句的核心部分
 #[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum SelectCore {
     Query {
         is_distinct: bool,
@@ -60,11 +82,13 @@ pub enum SelectCore {
         where_clause: Option<Expr>,
         group_by: Vec<Expr>,
         having: Option<Expr>,
+        window: Vec<(String, WindowDefn)>,
     },
     Values(Vec<Vec<Expr>>),
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum ResultColumn {
     Expr(Expr, Option<String>),
     Star,
@@ -93,7 +117,7 @@ pub struct SetSubClause {
 /// Join 子句
 #[derive(Clone, Debug, PartialEq)]
 pub struct JoinClause {
-    pub table_or_subquery: QualifiedTable,
+    pub table_or_subquery: TableOrSubquery,
     pub joins: Vec<JoinSubClause>,
 }
 
@@ -101,7 +125,7 @@ pub struct JoinClause {
 #[derive(Clone, Debug, PartialEq)]
 pub struct JoinSubClause {
     pub operator: JoinOperator,
-    pub table_or_subquery: QualifiedTable,
+    pub table_or_subquery: TableOrSubquery,
     pub constraint: Option<JoinConstraint>,
 }
 
@@ -128,6 +152,22 @@ pub enum JoinConstraint {
     Using(Vec<String>), // 列名
 }
 
+/// 表或子查询
+#[derive(Clone, Debug, PartialEq)]
+pub enum TableOrSubquery {
+    Table(QualifiedTable), // 普通表
+    TableFunction {
+        schema_function: SchemaObject,
+        args: Vec<Expr>,
+        alias: Option<String>,
+    }, // 表值函数
+    Subquery {
+        select: Box<Select>,
+        alias: Option<String>,
+    }, // 子查询
+    Join(Box<JoinClause>), // 括号中的 Join 子句
+}
+
 /// 完整的表标识，包括模式名、表名、别名和索引信息
 #[derive(Clone, Debug, PartialEq)]
 pub struct QualifiedTable {
@@ -146,7 +186,7 @@ pub enum Indexed {
 /// From 子句
 #[derive(Clone, Debug, PartialEq)]
 pub enum FromClause {
-    TableOrQuerys(Vec<QualifiedTable>),
+    TableOrQuerys(Vec<TableOrSubquery>),
     Join(JoinClause),
 }
 
@@ -160,6 +200,7 @@ pub struct OrderingTerm {
 
 /// Return 子句
 #[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum ReturnSubClause {
     Star,
     Expr(Expr, Option<String>),
@@ -181,6 +222,7 @@ pub struct UpsertSubClause {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum UpsertType {
     Nothing,
     Update {
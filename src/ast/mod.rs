@@ -1,11 +1,15 @@
+mod admin;
 mod ddl;
 mod dml;
 mod tcl;
 
+pub use crate::ast::admin::*;
 pub use crate::ast::ddl::*;
 pub use crate::ast::dml::*;
 pub use crate::ast::tcl::*;
 
+use std::ops::Range;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Stmt {
     // 数据操作语言（DML）
@@ -16,6 +20,7 @@ pub enum Stmt {
 
     // 数据定义语言（DDL）
     CreateTable(CreateTable),
+    CreateVirtualTable(CreateVirtualTable),
     CreateIndex(CreateIndex),
     CreateView(CreateView),
     CreateTrigger(CreateTrigger),
@@ -24,6 +29,36 @@ pub enum Stmt {
     DropIndex(DropIndex),
     DropView(DropView),
     DropTrigger(DropTrigger),
+
+    // 事务控制语言（TCL）
+    Begin(Begin),
+    Commit(Commit),
+    Rollback(Rollback),
+    Savepoint(Savepoint),
+    Release(Release),
+
+    // 数据库管理
+    Pragma(Pragma),
+    Attach(Attach),
+    Detach(Detach),
+    Vacuum(Vacuum),
+    Analyze(Analyze),
+    Reindex(Reindex),
+
+    // 执行计划
+    Explain {
+        query_plan: bool, // EXPLAIN QUERY PLAN
+        stmt: Box<Stmt>,
+    },
+}
+
+/// 带源码位置的语句
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpannedStmt {
+    pub stmt: Stmt,
+    pub span: Range<usize>, // 字节范围（含结尾分号）
+    pub line: usize,        // 起始行号，从 1 开始
+    pub col: usize,         // 起始列号，从 1 开始
 }
 
 /// 字面量
@@ -52,6 +87,123 @@ pub enum Expr {
         high: Box<Expr>,
     }, // BETWEEN 表达式
     QualifiedColumn(Option<String>, Option<String>, String), // 限定名称
+    Function {
+        name: String,
+        distinct: bool,
+        args: FunctionArgs,
+        filter: Option<Box<Expr>>,
+        order_by: Vec<OrderingTerm>,
+        over: Option<Over>,
+    }, // 函数调用
+    Case {
+        operand: Option<Box<Expr>>,
+        when_then: Vec<(Expr, Expr)>,
+        else_expr: Option<Box<Expr>>,
+    }, // CASE 表达式
+    Cast(Box<Expr>, TypeName),              // CAST 表达式
+    In {
+        expr: Box<Expr>,
+        not: bool,
+        rhs: InValues,
+    }, // IN 表达式
+    Like {
+        expr: Box<Expr>,
+        op: LikeOp,
+        not: bool,
+        pattern: Box<Expr>,
+        escape: Option<Box<Expr>>,
+    }, // LIKE、GLOB、REGEXP、MATCH 表达式
+    Exists {
+        not: bool,
+        select: Box<Select>,
+    }, // EXISTS 表达式
+    Subquery(Box<Select>),                  // 标量子查询
+    Param(Param),                           // 绑定参数
+    Collate(Box<Expr>, String),             // COLLATE 排序规则
+}
+
+/// 绑定参数
+#[derive(Clone, Debug, PartialEq)]
+pub enum Param {
+    Anonymous,       // ?
+    Numbered(usize), // ?NNN
+    Named(String),   // :name、@name 或 $name，包含前缀
+}
+
+/// IN 运算符的右侧
+#[derive(Clone, Debug, PartialEq)]
+pub enum InValues {
+    List(Vec<Expr>),     // 表达式列表，可以为空
+    Select(Box<Select>), // 子查询
+    Table(SchemaObject), // 表名
+}
+
+/// 模式匹配运算符
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LikeOp {
+    Like,   // LIKE
+    Glob,   // GLOB
+    Regexp, // REGEXP
+    Match,  // MATCH
+}
+
+/// 函数参数
+#[derive(Clone, Debug, PartialEq)]
+pub enum FunctionArgs {
+    Star,            // f(*)
+    List(Vec<Expr>), // f(a, b, ...)，可以为空
+}
+
+/// 窗口函数的 OVER 子句
+#[derive(Clone, Debug, PartialEq)]
+pub enum Over {
+    Name(String),       // OVER w
+    Window(WindowDefn), // OVER (...)
+}
+
+/// 窗口定义
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowDefn {
+    pub base: Option<String>, // 基础窗口名
+    pub partition_by: Vec<Expr>,
+    pub order_by: Vec<OrderingTerm>,
+    pub frame: Option<FrameSpec>,
+}
+
+/// 窗口帧
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameSpec {
+    pub unit: FrameUnit,
+    pub start: FrameBound,
+    pub end: FrameBound, // 未使用 BETWEEN 时为 CURRENT ROW
+    pub exclude: FrameExclude,
+}
+
+/// 窗口帧单位
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameUnit {
+    Range,
+    Rows,
+    Groups,
+}
+
+/// 窗口帧边界
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(Box<Expr>),
+    CurrentRow,
+    Following(Box<Expr>),
+    UnboundedFollowing,
+}
+
+/// 窗口帧排除方式（默认 NoOthers）
+#[derive(Clone, Debug, PartialEq)]
+pub enum FrameExclude {
+    NoOthers,
+    CurrentRow,
+    Group,
+    Ties,
 }
 
 /// 二元运算符
@@ -82,10 +234,12 @@ pub enum BinaryOp {
     Ge, // >=
 
     // level 5
-    Eq,    // = or ==
-    Ne,    // != or <>
-    Is,    // IS
-    IsNot, // IS NOT
+    Eq,                // = or ==
+    Ne,                // != or <>
+    Is,                // IS
+    IsNot,             // IS NOT
+    IsDistinctFrom,    // IS DISTINCT FROM
+    IsNotDistinctFrom, // IS NOT DISTINCT FROM
 
     // level 6
     LogicalAnd, // AND
@@ -99,6 +253,8 @@ pub enum UnaryOp {
     Positive,   // +
     Negative,   // -
     LogicalNot, // NOT
+    IsNull,     // ISNULL（后缀）
+    NotNull,    // NOTNULL 或 NOT NULL（后缀）
 }
 
 /// 模式名.对象名
@@ -0,0 +1,122 @@
+//! 解析错误定义
+
+use crate::Rule;
+use pest::{
+    Span,
+    error::{Error, ErrorVariant, InputLocation},
+    iterators::Pair,
+};
+use std::{fmt, ops::Range};
+
+/// 解析错误
+///
+/// 既可能来自 pest 的语法分析，也可能来自语法树到 AST 的转换。
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub stmt: Option<Rule>,       // 出错的语句类型
+    pub span: Range<usize>,       // 出错位置（字节范围）
+    pub line_col: (usize, usize), // 出错起始位置的行号和列号
+    pub expected: Vec<Rule>,      // 期望的语法结构
+    pub found: Option<Rule>,      // 实际遇到的语法结构
+    pub snippet: String,          // 带源码片段的错误描述
+    pub error: Box<Error<Rule>>,  // 底层的 pest 错误
+}
+
+impl ParseError {
+    /// 遇到了不符合预期的语法结构
+    pub(crate) fn unexpected(pair: &Pair<Rule>, expected: &[Rule]) -> Self {
+        let variant = ErrorVariant::ParsingError {
+            positives: expected.to_vec(),
+            negatives: vec![pair.as_rule()],
+        };
+        let mut err = Self::from(Error::new_from_span(variant, pair.as_span()));
+        err.found = Some(pair.as_rule());
+        err
+    }
+
+    /// 语法结构缺少必需的子节点
+    pub(crate) fn missing(rule: Rule, span: Span) -> Self {
+        let variant = ErrorVariant::CustomError {
+            message: format!("incomplete {:?}", rule),
+        };
+        let mut err = Self::from(Error::new_from_pos(variant, span.end_pos()));
+        err.found = Some(rule);
+        err
+    }
+
+    /// 记录出错的语句类型（已记录时保持不变）
+    pub(crate) fn in_stmt(mut self, rule: Rule) -> Self {
+        self.stmt.get_or_insert(rule);
+        self
+    }
+}
+
+/// 关键字边界规则仅用于前瞻，不作为期望的语法结构报告
+fn is_lookahead(rule: &Rule) -> bool {
+    matches!(
+        rule,
+        Rule::kw_as
+            | Rule::kw_in
+            | Rule::kw_exists
+            | Rule::kw_savepoint
+            | Rule::kw_database
+            | Rule::kw_over
+            | Rule::kw_collate
+            | Rule::kw_is
+            | Rule::kw_not
+            | Rule::kw_null
+            | Rule::kw_isnull
+            | Rule::kw_notnull
+    )
+}
+
+impl From<Error<Rule>> for ParseError {
+    fn from(mut error: Error<Rule>) -> Self {
+        if let ErrorVariant::ParsingError {
+            positives,
+            negatives,
+        } = &mut error.variant
+        {
+            positives.retain(|rule| !is_lookahead(rule));
+            negatives.retain(|rule| !is_lookahead(rule));
+        }
+
+        let span = match error.location {
+            InputLocation::Pos(pos) => pos..pos,
+            InputLocation::Span((start, end)) => start..end,
+        };
+        let line_col = match error.line_col {
+            pest::error::LineColLocation::Pos(pos) => pos,
+            pest::error::LineColLocation::Span(start, _) => start,
+        };
+        let expected = match &error.variant {
+            ErrorVariant::ParsingError { positives, .. } => positives.clone(),
+            ErrorVariant::CustomError { .. } => vec![],
+        };
+
+        Self {
+            stmt: None,
+            span,
+            line_col,
+            expected,
+            found: None,
+            snippet: error.to_string(),
+            error: Box::new(error),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.stmt {
+            Some(rule) => write!(f, "failed to parse {:?} statement\n{}", rule, self.snippet),
+            None => write!(f, "{}", self.snippet),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
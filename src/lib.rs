@@ -1,7 +1,9 @@
 mod ast;
+mod error;
 mod parser;
 
 pub use crate::ast::*;
+pub use crate::error::ParseError;
 pub use crate::parser::Parser;
 use pest::Parser as PestParser;
 
@@ -10,7 +12,57 @@ use pest::Parser as PestParser;
 pub struct SqlParser;
 
 /// Parse a SQL statement into an AST.
-pub fn parse_stmt(input: &str) -> Result<Vec<Stmt>, pest::error::Error<Rule>> {
+pub fn parse_stmt(input: &str) -> Result<Vec<Stmt>, ParseError> {
     let pairs = SqlParser::parse(Rule::stmt, input)?;
-    Ok(pairs.map(|p| Stmt::parse(p)).collect())
+    pairs.map(Stmt::parse).collect()
+}
+
+/// Parse a SQL script containing any number of statements, keeping the
+/// source location of each statement.
+pub fn parse_script(input: &str) -> Result<Vec<SpannedStmt>, ParseError> {
+    let pairs = SqlParser::parse(Rule::stmts, input)?;
+    pairs
+        .filter(|p| p.as_rule() == Rule::stmt)
+        .map(|p| {
+            let span = p.as_span();
+            let (line, col) = span.start_pos().line_col();
+            Ok(SpannedStmt {
+                span: span.start()..span.end(),
+                line,
+                col,
+                stmt: Stmt::parse(p)?,
+            })
+        })
+        .collect()
+}
+
+/// List the bind parameters of a SQL statement in binding order.
+///
+/// Indexes follow SQLite's rules: `?` takes the largest index assigned so
+/// far plus one, `?NNN` takes index NNN, and a named parameter reuses the
+/// index of its first occurrence. Each index is listed once.
+pub fn parse_params(input: &str) -> Result<Vec<(usize, Param)>, ParseError> {
+    let pairs = SqlParser::parse(Rule::stmt, input)?;
+
+    let mut params: Vec<(usize, Param)> = vec![];
+    let mut max_index = 0;
+    for pair in pairs.flatten().filter(|p| p.as_rule() == Rule::param) {
+        let param = Param::parse(pair)?;
+        let index = match &param {
+            Param::Anonymous => max_index + 1,
+            Param::Numbered(n) => *n,
+            Param::Named(_) => match params.iter().find(|(_, p)| *p == param) {
+                Some((i, _)) => *i,
+                None => max_index + 1,
+            },
+        };
+        max_index = max_index.max(index);
+
+        if params.iter().all(|(i, _)| *i != index) {
+            params.push((index, param));
+        }
+    }
+
+    params.sort_by_key(|(i, _)| *i);
+    Ok(params)
 }
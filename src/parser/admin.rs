@@ -0,0 +1,110 @@
+use crate::{
+    ParseError, Rule,
+    ast::*,
+    parser::{Children, Parser, parse_signed_number},
+};
+use pest::iterators::Pair;
+
+impl Parser for Pragma {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+
+        // 解析模式名.PRAGMA 名
+        let name = SchemaObject::parse(inner.expect()?)?;
+
+        // 解析取值（可选）
+        let value = inner.next().map(PragmaValue::parse).transpose()?;
+
+        Ok(Self { name, value })
+    }
+}
+
+impl Parser for PragmaValue {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
+
+        match pair.as_rule() {
+            Rule::signed_number => Ok(Self::Number(parse_signed_number(pair)?)),
+            Rule::double => Ok(Self::Number(Literal::Double(pair.as_str().to_owned()))),
+            Rule::decimal => Ok(Self::Number(Literal::Decimal(pair.as_str().to_owned()))),
+            Rule::integer => Ok(Self::Number(Literal::Integer(pair.as_str().to_owned()))),
+            Rule::string => {
+                let str = pair.as_str();
+                Ok(Self::String(str[1..str.len() - 1].to_owned()))
+            }
+            Rule::ident => Ok(Self::Name(String::parse(pair)?)),
+            Rule::pragma_name => Ok(Self::Name(pair.as_str().to_lowercase())),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[
+                    Rule::signed_number,
+                    Rule::double,
+                    Rule::decimal,
+                    Rule::integer,
+                    Rule::string,
+                    Rule::ident,
+                    Rule::pragma_name,
+                ],
+            )),
+        }
+    }
+}
+
+impl Parser for Attach {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+
+        // 解析数据库文件名表达式
+        let expr = Expr::parse(inner.expect()?)?;
+
+        // 解析模式名
+        let schema_name = String::parse(inner.expect()?)?;
+
+        Ok(Self { expr, schema_name })
+    }
+}
+
+impl Parser for Detach {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let schema_name = String::parse(Children::new(pair).expect()?)?;
+        Ok(Self(schema_name))
+    }
+}
+
+impl Parser for Vacuum {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.next();
+
+        // 解析模式名（可选）
+        let (schema_name, pair) = match pair {
+            Some(p) if p.as_rule() == Rule::ident => (Some(String::parse(p)?), inner.next()),
+            _ => (None, pair),
+        };
+
+        // 解析 INTO 目标（可选）
+        let into = pair.map(Expr::parse).transpose()?;
+
+        Ok(Self { schema_name, into })
+    }
+}
+
+impl Parser for Analyze {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let name = Children::new(pair)
+            .next()
+            .map(SchemaObject::parse)
+            .transpose()?;
+        Ok(Self(name))
+    }
+}
+
+impl Parser for Reindex {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let name = Children::new(pair)
+            .next()
+            .map(SchemaObject::parse)
+            .transpose()?;
+        Ok(Self(name))
+    }
+}
@@ -1,113 +1,162 @@
-use crate::{Rule, ast::*, parser::Parser};
+use crate::{
+    ParseError, Rule,
+    ast::*,
+    parser::{Children, Parser, parse_signed_number},
+};
 use pest::iterators::Pair;
 
 impl Parser for CreateTable {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 是否为临时表（可选）
         let (temp, pair) = match pair.as_rule() {
-            Rule::temp => (true, inner.next().unwrap()),
+            Rule::temp => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 是否存在则不创建（可选）
         let (if_not_exists, pair) = match pair.as_rule() {
-            Rule::if_not_exists => (true, inner.next().unwrap()),
+            Rule::if_not_exists => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析模式名.表名
-        let schema_table = SchemaObject::parse(pair);
-        let pair = inner.next().unwrap();
+        let schema_table = SchemaObject::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析表体
-        let body = CreateTableBody::parse(pair);
+        let body = CreateTableBody::parse(pair)?;
 
-        Self {
+        Ok(Self {
             temp,
             if_not_exists,
             schema_table,
             body,
-        }
+        })
+    }
+}
+
+impl Parser for CreateVirtualTable {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
+
+        // 是否存在则不创建（可选）
+        let (if_not_exists, pair) = match pair.as_rule() {
+            Rule::if_not_exists => (true, inner.expect()?),
+            _ => (false, pair),
+        };
+
+        // 解析模式名.表名
+        let schema_table = SchemaObject::parse(pair)?;
+
+        // 解析模块名
+        let module = String::parse(inner.expect()?)?;
+
+        // 解析模块参数（可选），去掉首尾空白
+        let args = match inner.next() {
+            Some(pair) => pair
+                .into_inner()
+                .map(|arg| arg.as_str().trim().to_owned())
+                .collect(),
+            None => vec![],
+        };
+
+        Ok(Self {
+            if_not_exists,
+            schema_table,
+            module,
+            args,
+        })
     }
 }
 
 impl Parser for DropTable {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 是否存在则删除（可选）
         let (if_exists, pair) = match pair.as_rule() {
-            Rule::if_exists => (true, inner.next().unwrap()),
+            Rule::if_exists => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析模式名.表名
-        let schema_table = SchemaObject::parse(pair);
+        let schema_table = SchemaObject::parse(pair)?;
 
-        Self {
+        Ok(Self {
             if_exists,
             schema_table,
-        }
+        })
     }
 }
 
 impl Parser for AlterTable {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析模式名.表名
-        let schema_table = SchemaObject::parse(pair);
-        let pair = inner.next().unwrap();
+        let schema_table = SchemaObject::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析改表操作
         let action = match pair.as_rule() {
             Rule::alter_table_action1 => {
-                let name = String::parse(pair.into_inner().next().unwrap());
+                let name = String::parse(Children::new(pair).expect()?)?;
                 AlterTableAction::RenameTable(name)
             }
             Rule::alter_table_action2 => {
-                let mut inner = pair.into_inner();
-                let old_name = String::parse(inner.next().unwrap());
-                let new_name = String::parse(inner.next().unwrap());
+                let mut inner = Children::new(pair);
+                let old_name = String::parse(inner.expect()?)?;
+                let new_name = String::parse(inner.expect()?)?;
                 AlterTableAction::RenameColumn(old_name, new_name)
             }
             Rule::alter_table_action3 => {
-                let column_def = ColumnDef::parse(pair.into_inner().next().unwrap());
+                let column_def = ColumnDef::parse(Children::new(pair).expect()?)?;
                 AlterTableAction::AddColumn(column_def)
             }
             Rule::alter_table_action4 => {
-                let name = String::parse(pair.into_inner().next().unwrap());
+                let name = String::parse(Children::new(pair).expect()?)?;
                 AlterTableAction::DropColumn(name)
             }
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[
+                        Rule::alter_table_action1,
+                        Rule::alter_table_action2,
+                        Rule::alter_table_action3,
+                        Rule::alter_table_action4,
+                    ],
+                ));
+            }
         };
 
-        Self {
+        Ok(Self {
             schema_table,
             action,
-        }
+        })
     }
 }
 
 impl Parser for ColumnConstraint {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析约束名称（可选）
         let (name, pair) = match pair.as_rule() {
-            Rule::ident => (Some(String::parse(pair)), inner.next().unwrap()),
+            Rule::ident => (Some(String::parse(pair)?), inner.expect()?),
             _ => (None, pair),
         };
 
         let ty = match pair.as_rule() {
             Rule::column_constraint1 => {
-                let mut inner = pair.into_inner();
+                let mut inner = Children::new(pair);
                 let pair = inner.next();
 
                 // 解析排序方式（可选）
@@ -118,102 +167,338 @@ impl Parser for ColumnConstraint {
                     _ => (true, pair),
                 };
 
+                // 解析冲突解决策略（可选）
+                let (conflict, pair) = match pair {
+                    Some(p) if p.as_rule() == Rule::conflict_clause => {
+                        (Some(parse_conflict_clause(p)?), inner.next())
+                    }
+                    _ => (None, pair),
+                };
+
                 // 解析自动递增（可选）
                 let auto_inc = pair.is_some();
 
                 ColumnConstraintType::PrimaryKey {
                     asc: order,
+                    conflict,
                     auto_inc,
                 }
             }
-            Rule::column_constraint2 => ColumnConstraintType::NotNull,
-            Rule::column_constraint3 => ColumnConstraintType::Unique,
+            Rule::column_constraint2 => ColumnConstraintType::NotNull {
+                conflict: Children::new(pair)
+                    .next()
+                    .map(parse_conflict_clause)
+                    .transpose()?,
+            },
+            Rule::column_constraint3 => ColumnConstraintType::Unique {
+                conflict: Children::new(pair)
+                    .next()
+                    .map(parse_conflict_clause)
+                    .transpose()?,
+            },
             Rule::column_constraint4 => {
-                let expr_pair = pair.into_inner().next().unwrap();
-                ColumnConstraintType::Check(Expr::parse(expr_pair))
+                let expr_pair = Children::new(pair).expect()?;
+                ColumnConstraintType::Check(Expr::parse(expr_pair)?)
             }
             Rule::column_constraint5 => {
-                let literal_pair = pair.into_inner().next().unwrap();
-                ColumnConstraintType::Default(Literal::parse(literal_pair))
+                let value_pair = Children::new(pair).expect()?;
+                ColumnConstraintType::Default(DefaultValue::parse(value_pair)?)
+            }
+            Rule::column_constraint6 => {
+                let collation = String::parse(Children::new(pair).expect()?)?;
+                ColumnConstraintType::Collate(collation)
+            }
+            Rule::column_constraint7 => {
+                let clause = ForeignKeyClause::parse(Children::new(pair).expect()?)?;
+                ColumnConstraintType::ForeignKey(clause)
+            }
+            Rule::column_constraint8 => {
+                let mut inner = Children::new(pair);
+                let expr = Expr::parse(inner.expect()?)?;
+                let stored = inner.next().is_some_and(|p| p.as_rule() == Rule::stored);
+                ColumnConstraintType::Generated { expr, stored }
+            }
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[
+                        Rule::column_constraint1,
+                        Rule::column_constraint2,
+                        Rule::column_constraint3,
+                        Rule::column_constraint4,
+                        Rule::column_constraint5,
+                        Rule::column_constraint6,
+                        Rule::column_constraint7,
+                        Rule::column_constraint8,
+                    ],
+                ));
             }
-            rule => panic!("Unexpected rule: {:?}", rule),
         };
 
-        Self { name, ty }
+        Ok(Self { name, ty })
     }
 }
 
 impl Parser for ColumnDef {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析列名
-        let col_name = String::parse(pair);
+        let col_name = String::parse(pair)?;
         let pair = inner.next();
 
         // 解析列类型（可选）
         let (col_type, pair) = match pair {
-            Some(p) if p.as_rule() == Rule::type_name => (Some(TypeName::parse(p)), inner.next()),
+            Some(p) if p.as_rule() == Rule::type_name => (Some(TypeName::parse(p)?), inner.next()),
             _ => (None, pair),
         };
 
         // 解析列级约束
-        let constraints = pair.map_or(vec![], |p| {
-            p.into_inner().map(|p| ColumnConstraint::parse(p)).collect()
-        });
+        let constraints = match pair {
+            Some(p) => p
+                .into_inner()
+                .map(ColumnConstraint::parse)
+                .collect::<Result<_, _>>()?,
+            None => vec![],
+        };
 
-        Self {
+        Ok(Self {
             col_name,
             col_type,
             constraints,
-        }
+        })
     }
 }
 
 impl Parser for TableConstraint {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析约束名称（可选）
         let (name, pair) = match pair.as_rule() {
-            Rule::ident => (Some(String::parse(pair)), inner.next().unwrap()),
+            Rule::ident => (Some(String::parse(pair)?), inner.expect()?),
             _ => (None, pair),
         };
 
-        // 解析约束类型和列
+        // 解析约束类型
         let ty = match pair.as_rule() {
-            Rule::table_constraint1 => TableConstraintType::PrimaryKey,
-            Rule::table_constraint2 => TableConstraintType::Unique,
-            rule => panic!("Unexpected rule: {:?}", rule),
+            Rule::table_constraint1 => {
+                let (cols, conflict) = parse_key_constraint(pair)?;
+                TableConstraintType::PrimaryKey { cols, conflict }
+            }
+            Rule::table_constraint2 => {
+                let (cols, conflict) = parse_key_constraint(pair)?;
+                TableConstraintType::Unique { cols, conflict }
+            }
+            Rule::table_constraint3 => {
+                let mut inner = Children::new(pair);
+                let columns = inner
+                    .expect()?
+                    .into_inner()
+                    .map(String::parse)
+                    .collect::<Result<_, _>>()?;
+                let clause = ForeignKeyClause::parse(inner.expect()?)?;
+                TableConstraintType::ForeignKey { columns, clause }
+            }
+            Rule::table_constraint4 => {
+                TableConstraintType::Check(Expr::parse(Children::new(pair).expect()?)?)
+            }
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[
+                        Rule::table_constraint1,
+                        Rule::table_constraint2,
+                        Rule::table_constraint3,
+                        Rule::table_constraint4,
+                    ],
+                ));
+            }
+        };
+
+        Ok(Self { name, ty })
+    }
+}
+
+impl Parser for DefaultValue {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
+
+        match pair.as_rule() {
+            Rule::literal => Ok(Self::Literal(Literal::parse(pair)?)),
+            Rule::signed_number => Ok(Self::SignedNumber(parse_signed_number(pair)?)),
+            Rule::expr => Ok(Self::Expr(Expr::parse(pair)?)),
+            Rule::current_time => Ok(Self::CurrentTime),
+            Rule::current_date => Ok(Self::CurrentDate),
+            Rule::current_timestamp => Ok(Self::CurrentTimestamp),
+            Rule::ident => Ok(Self::Ident(String::parse(pair)?)),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[
+                    Rule::signed_number,
+                    Rule::literal,
+                    Rule::expr,
+                    Rule::current_timestamp,
+                    Rule::current_time,
+                    Rule::current_date,
+                    Rule::ident,
+                ],
+            )),
+        }
+    }
+}
+
+/// 解析 PRIMARY KEY / UNIQUE 表级约束的列和冲突解决策略
+fn parse_key_constraint(
+    pair: Pair<Rule>,
+) -> Result<(Vec<IndexedColumn>, Option<ConflictResolution>), ParseError> {
+    let mut inner = Children::new(pair);
+    let cols = inner
+        .expect()?
+        .into_inner()
+        .map(IndexedColumn::parse)
+        .collect::<Result<_, _>>()?;
+    let conflict = inner.next().map(parse_conflict_clause).transpose()?;
+
+    Ok((cols, conflict))
+}
+
+/// 解析约束冲突子句 ON CONFLICT
+fn parse_conflict_clause(pair: Pair<Rule>) -> Result<ConflictResolution, ParseError> {
+    ConflictResolution::parse(Children::new(pair).expect()?)
+}
+
+impl Parser for ForeignKeyClause {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+
+        // 解析外部表名
+        let foreign_table = String::parse(inner.expect()?)?;
+        let pair = inner.next();
+
+        // 解析外部表列名（可选）
+        let (columns, pair) = match pair {
+            Some(p) if p.as_rule() == Rule::idents => (
+                p.into_inner()
+                    .map(String::parse)
+                    .collect::<Result<_, _>>()?,
+                inner.next(),
+            ),
+            _ => (vec![], pair),
+        };
+
+        // 解析 ON DELETE / ON UPDATE / MATCH / DEFERRABLE（可选，顺序任意）
+        let mut on_delete = FkAction::NoAction;
+        let mut on_update = FkAction::NoAction;
+        let mut match_name = None;
+        let mut deferrable = None;
+        for p in pair.into_iter().chain(inner) {
+            match p.as_rule() {
+                Rule::fk_on_delete => on_delete = FkAction::parse(Children::new(p).expect()?)?,
+                Rule::fk_on_update => on_update = FkAction::parse(Children::new(p).expect()?)?,
+                Rule::fk_match => match_name = Some(String::parse(Children::new(p).expect()?)?),
+                Rule::fk_deferrable => deferrable = Some(Deferrable::parse(p)?),
+                _ => {
+                    return Err(ParseError::unexpected(
+                        &p,
+                        &[
+                            Rule::fk_on_delete,
+                            Rule::fk_on_update,
+                            Rule::fk_match,
+                            Rule::fk_deferrable,
+                        ],
+                    ));
+                }
+            }
+        }
+
+        Ok(Self {
+            foreign_table,
+            columns,
+            on_delete,
+            on_update,
+            match_name,
+            deferrable,
+        })
+    }
+}
+
+impl Parser for FkAction {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        match pair.as_rule() {
+            Rule::fk_set_null => Ok(Self::SetNull),
+            Rule::fk_set_default => Ok(Self::SetDefault),
+            Rule::fk_cascade => Ok(Self::Cascade),
+            Rule::fk_restrict => Ok(Self::Restrict),
+            Rule::fk_no_action => Ok(Self::NoAction),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[
+                    Rule::fk_set_null,
+                    Rule::fk_set_default,
+                    Rule::fk_cascade,
+                    Rule::fk_restrict,
+                    Rule::fk_no_action,
+                ],
+            )),
+        }
+    }
+}
+
+impl Parser for Deferrable {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.next();
+
+        // 解析 NOT（可选）
+        let (not, pair) = match pair {
+            Some(p) if p.as_rule() == Rule::not => (true, inner.next()),
+            _ => (false, pair),
         };
 
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
-        let cols = pair.into_inner().map(|p| IndexedColumn::parse(p)).collect();
+        // 解析 INITIALLY DEFERRED / IMMEDIATE（可选）
+        let initially = match pair {
+            Some(p) if p.as_rule() == Rule::deferred => Some(Initially::Deferred),
+            Some(p) if p.as_rule() == Rule::immediate => Some(Initially::Immediate),
+            Some(p) => {
+                return Err(ParseError::unexpected(
+                    &p,
+                    &[Rule::deferred, Rule::immediate],
+                ));
+            }
+            None => None,
+        };
 
-        Self { name, cols, ty }
+        Ok(Self { not, initially })
     }
 }
 
 impl Parser for IndexedColumn {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let name = inner.next().unwrap().as_str().to_owned();
-        let asc = inner.next().map_or(true, |p| p.as_rule() == Rule::asc);
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+
+        // 顶层的 COLLATE 作为列的排序规则
+        let (expr, collation) = match Expr::parse(inner.expect()?)? {
+            Expr::Collate(expr, collation) => (*expr, Some(collation)),
+            expr => (expr, None),
+        };
+
+        let asc = inner.next().is_none_or(|p| p.as_rule() == Rule::asc);
 
-        Self { name, asc }
+        Ok(Self {
+            expr,
+            collation,
+            asc,
+        })
     }
 }
 
 impl Parser for TypeName {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
-
-        println!("{:?}", pair);
-        let name = String::parse(pair);
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let name = String::parse(inner.expect()?)?;
 
         let size = match (inner.next(), inner.next()) {
             (Some(first), Some(second)) => Some(TypeSize::TypeSize(
@@ -224,263 +509,276 @@ impl Parser for TypeName {
             _ => None,
         };
 
-        Self { name, size }
+        Ok(Self { name, size })
     }
 }
 
 impl Parser for CreateView {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析是否为临时视图（可选）
         let (temp, pair) = match pair.as_rule() {
-            Rule::temp => (true, inner.next().unwrap()),
+            Rule::temp => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析 if not exists（可选）
         let (if_not_exists, pair) = match pair.as_rule() {
-            Rule::if_not_exists => (true, inner.next().unwrap()),
+            Rule::if_not_exists => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析模式名.视图名
-        let schema_view = SchemaObject::parse(pair);
-        let pair = inner.next().unwrap();
+        let schema_view = SchemaObject::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析视图列（可选）
         let (columns, pair) = match pair.as_rule() {
             Rule::idents => {
-                let cols: Vec<_> = pair.into_inner().map(|p| String::parse(p)).collect();
-                (cols, inner.next().unwrap())
+                let cols = pair
+                    .into_inner()
+                    .map(String::parse)
+                    .collect::<Result<_, _>>()?;
+                (cols, inner.expect()?)
             }
             _ => (vec![], pair),
         };
 
         // 解析 SELECT 语句
-        let select = Select::parse(pair);
+        let select = Select::parse(pair)?;
 
-        Self {
+        Ok(Self {
             temp,
             if_not_exists,
             schema_view,
             columns,
             select,
-        }
+        })
     }
 }
 
 impl Parser for DropView {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析 if exists
         let (if_exists, pair) = match pair.as_rule() {
-            Rule::if_exists => (true, inner.next().unwrap()),
+            Rule::if_exists => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析模式名.视图名
-        let schema_view = SchemaObject::parse(pair);
+        let schema_view = SchemaObject::parse(pair)?;
 
-        Self {
+        Ok(Self {
             if_exists,
             schema_view,
-        }
+        })
     }
 }
 
 impl Parser for CreateIndex {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析是否为唯一索引
         let (unique, pair) = match pair.as_rule() {
-            Rule::unique => (true, inner.next().unwrap()),
+            Rule::unique => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析 if not exists
         let (if_not_exists, pair) = match pair.as_rule() {
-            Rule::if_not_exists => (true, inner.next().unwrap()),
+            Rule::if_not_exists => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析模式名.索引名
-        let schema_index = SchemaObject::parse(pair);
-        let pair = inner.next().unwrap();
+        let schema_index = SchemaObject::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析表名
         let table_name = pair.as_str().to_owned();
-        let pair = inner.next().unwrap();
+        let pair = inner.expect()?;
 
         // 解析索引列
-        let indexed_cols: Vec<_> = pair.into_inner().map(|p| IndexedColumn::parse(p)).collect();
+        let indexed_cols = pair
+            .into_inner()
+            .map(IndexedColumn::parse)
+            .collect::<Result<_, _>>()?;
         let pair = inner.next();
 
         // 解析 WHERE 子句（可选）
-        let where_cond = pair.map(|p| Expr::parse(p));
+        let where_cond = pair.map(Expr::parse).transpose()?;
 
-        Self {
+        Ok(Self {
             unique,
             if_not_exists,
             schema_index,
             table_name,
             indexed_cols,
             where_cond,
-        }
+        })
     }
 }
 
 impl Parser for DropIndex {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析 if exists
         let (if_exists, pair) = match pair.as_rule() {
-            Rule::if_exists => (true, inner.next().unwrap()),
+            Rule::if_exists => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析模式名.视图名
-        let schema_index = SchemaObject::parse(pair);
+        let schema_index = SchemaObject::parse(pair)?;
 
-        Self {
+        Ok(Self {
             if_exists,
             schema_index,
-        }
+        })
     }
 }
 
 impl Parser for CreateTableBody {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         match pair.as_rule() {
             Rule::select => {
-                let select = Select::parse(pair);
-                Self::Select(select)
+                let select = Select::parse(pair)?;
+                Ok(Self::Select(select))
             }
             Rule::column_defs => {
                 // 解析列定义
                 let columns = pair
                     .into_inner()
-                    .map(|p| ColumnDef::parse(p))
-                    .collect::<Vec<_>>();
-                let pair = inner.next().unwrap();
+                    .map(ColumnDef::parse)
+                    .collect::<Result<_, _>>()?;
+                let pair = inner.expect()?;
 
                 // 解析表级约束（可选）
                 let table_constraints = pair
                     .into_inner()
-                    .map(|p| TableConstraint::parse(p))
-                    .collect();
+                    .map(TableConstraint::parse)
+                    .collect::<Result<_, _>>()?;
 
                 // 解析表选项（可选）
-                let table_options: Vec<_> = inner
+                let table_options = inner
                     .map(|p| match p.as_rule() {
-                        Rule::without_rowid => TableOption::WithoutRowid,
-                        Rule::strict => TableOption::Strict,
-                        rule => unreachable!("Unexpected rule: {:?}", rule),
+                        Rule::without_rowid => Ok(TableOption::WithoutRowid),
+                        Rule::strict => Ok(TableOption::Strict),
+                        _ => Err(ParseError::unexpected(
+                            &p,
+                            &[Rule::without_rowid, Rule::strict],
+                        )),
                     })
-                    .collect();
+                    .collect::<Result<_, _>>()?;
 
-                Self::Columns {
+                Ok(Self::Columns {
                     columns,
                     table_constraints,
                     table_options,
-                }
+                })
             }
-            rule => unreachable!("Unexpected rule: {:?}", rule),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[Rule::select, Rule::column_defs],
+            )),
         }
     }
 }
 
 impl Parser for CreateTrigger {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析是否为临时触发器（可选）
         let (temp, pair) = match pair.as_rule() {
-            Rule::temp => (true, inner.next().unwrap()),
+            Rule::temp => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析是否存在则不创建（可选）
         let (if_not_exists, pair) = match pair.as_rule() {
-            Rule::if_not_exists => (true, inner.next().unwrap()),
+            Rule::if_not_exists => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析模式名.触发器名
-        let schema_trigger = SchemaObject::parse(pair);
-        let pair = inner.next().unwrap();
+        let schema_trigger = SchemaObject::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析触发器时机
         let timing = match pair.as_rule() {
             Rule::before => TriggerTiming::Before,
             Rule::after => TriggerTiming::After,
             Rule::instead => TriggerTiming::InsteadOf,
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[Rule::before, Rule::after, Rule::instead],
+                ));
+            }
         };
-        let pair = inner.next().unwrap();
+        let pair = inner.expect()?;
 
         // 解析触发器事件
         let event = match pair.as_rule() {
             Rule::trigger_event1 => TriggerEvent::Delete,
             Rule::trigger_event2 => TriggerEvent::Insert,
             Rule::trigger_event3 => {
-                let mut inner = pair.into_inner();
-                let cols: Vec<_> = inner.next().map_or(vec![], |p| {
-                    p.into_inner().map(|p| String::parse(p)).collect()
-                });
+                let cols = match Children::new(pair).next() {
+                    Some(p) => p
+                        .into_inner()
+                        .map(String::parse)
+                        .collect::<Result<_, _>>()?,
+                    None => vec![],
+                };
                 TriggerEvent::Update(cols)
             }
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[
+                        Rule::trigger_event1,
+                        Rule::trigger_event2,
+                        Rule::trigger_event3,
+                    ],
+                ));
+            }
         };
-        let pair = inner.next().unwrap();
+        let pair = inner.expect()?;
 
         // 解析表名
-        let table_name = String::parse(pair);
-        let pair = inner.next().unwrap();
+        let table_name = String::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析 WHEN 条件（可选）
         let (when_cond, pair) = match pair.as_rule() {
             Rule::when_clause => {
-                let expr_pair = pair.into_inner().next().unwrap();
-                (Some(Expr::parse(expr_pair)), inner.next().unwrap())
+                let expr_pair = Children::new(pair).expect()?;
+                (Some(Expr::parse(expr_pair)?), inner.expect()?)
             }
             _ => (None, pair),
         };
 
         // 解析触发器语句
-        let mut statements = vec![];
-        let first = match pair.as_rule() {
-            Rule::select => Dml::Select(Select::parse(pair)),
-            Rule::insert => Dml::Insert(Insert::parse(pair)),
-            Rule::update => Dml::Update(Update::parse(pair)),
-            Rule::delete => Dml::Delete(Delete::parse(pair)),
-            _ => panic!("Unexpected rule: {:?}", pair),
-        };
-        statements.push(first);
-
-        for p in inner {
-            let stmt = match p.as_rule() {
-                Rule::select => Dml::Select(Select::parse(p)),
-                Rule::insert => Dml::Insert(Insert::parse(p)),
-                Rule::update => Dml::Update(Update::parse(p)),
-                Rule::delete => Dml::Delete(Delete::parse(p)),
-                _ => panic!("Unexpected rule: {:?}", p),
-            };
-            statements.push(stmt);
-        }
+        let statements = std::iter::once(pair)
+            .chain(inner)
+            .map(Dml::parse)
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        Ok(Self {
             temp,
             if_not_exists,
             schema_trigger,
@@ -489,27 +787,27 @@ impl Parser for CreateTrigger {
             table_name,
             when_cond,
             statements,
-        }
+        })
     }
 }
 
 impl Parser for DropTrigger {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析 if exists
         let (if_exists, pair) = match pair.as_rule() {
-            Rule::if_exists => (true, inner.next().unwrap()),
+            Rule::if_exists => (true, inner.expect()?),
             _ => (false, pair),
         };
 
         // 解析模式名.触发器名
-        let schema_trigger = SchemaObject::parse(pair);
+        let schema_trigger = SchemaObject::parse(pair)?;
 
-        Self {
+        Ok(Self {
             if_exists,
             schema_trigger,
-        }
+        })
     }
 }
@@ -1,13 +1,38 @@
-use crate::{Rule, ast::*, parser::Parser};
+use crate::{
+    ParseError, Rule,
+    ast::*,
+    parser::{Children, Parser},
+};
 use pest::iterators::Pair;
 
+impl Parser for Dml {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        match pair.as_rule() {
+            Rule::select => Select::parse(pair).map(Dml::Select),
+            Rule::insert => Insert::parse(pair).map(Dml::Insert),
+            Rule::update => Update::parse(pair).map(Dml::Update),
+            Rule::delete => Delete::parse(pair).map(Dml::Delete),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[Rule::select, Rule::insert, Rule::update, Rule::delete],
+            )),
+        }
+    }
+}
+
 impl Parser for Select {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
+
+        // 解析 WITH 子句（可选）
+        let (with_clause, pair) = match pair.as_rule() {
+            Rule::with_clause => (Some(WithClause::parse(pair)?), inner.expect()?),
+            _ => (None, pair),
+        };
 
         // 解析 SELECT 语句核心
-        let core = SelectCore::parse(pair);
+        let core = SelectCore::parse(pair)?;
         let mut pair = inner.next();
 
         // 解析复合操作符和后续的 SELECT 语句核心
@@ -15,20 +40,23 @@ impl Parser for Select {
         loop {
             match pair {
                 Some(p) if p.as_rule() == Rule::compound_operator => {
-                    println!("P: {:?}", p);
-
                     // 解析复合操作符
-                    let inside = p.into_inner().next().unwrap();
+                    let inside = Children::new(p).expect()?;
                     let operator = match inside.as_rule() {
                         Rule::union => CompoundOperator::Union(false),
                         Rule::union_all => CompoundOperator::Union(true),
                         Rule::intersect => CompoundOperator::Intersect,
                         Rule::except => CompoundOperator::Except,
-                        rule => panic!("Unexpected rule: {:?}", rule),
+                        _ => {
+                            return Err(ParseError::unexpected(
+                                &inside,
+                                &[Rule::union, Rule::union_all, Rule::intersect, Rule::except],
+                            ));
+                        }
                     };
 
                     // 解析下一个 SELECT 语句核心
-                    let next_core = SelectCore::parse(inner.next().unwrap());
+                    let next_core = SelectCore::parse(inner.expect()?)?;
 
                     // 添加到复合列表中
                     compound.push((operator, next_core));
@@ -43,10 +71,10 @@ impl Parser for Select {
         // 解析 ORDER BY 子句（可选）
         let (order_by, pair) = match pair {
             Some(p) if p.as_rule() == Rule::ordering_terms => {
-                let ordering_terms: Vec<OrderingTerm> = p
+                let ordering_terms = p
                     .into_inner()
-                    .map(|pair| OrderingTerm::parse(pair))
-                    .collect();
+                    .map(OrderingTerm::parse)
+                    .collect::<Result<_, _>>()?;
                 (ordering_terms, inner.next())
             }
             _ => (vec![], pair),
@@ -55,48 +83,112 @@ impl Parser for Select {
         // 解析 LIMIT 子句（可选）
         let (limit, offset) = match pair {
             Some(limit_pair) => {
-                let limit = Expr::parse(limit_pair);
-                let offset = inner.next().map(|pair| Expr::parse(pair));
+                let limit = Expr::parse(limit_pair)?;
+                let offset = inner.next().map(Expr::parse).transpose()?;
                 (Some(limit), offset)
             }
             _ => (None, None),
         };
 
-        Self {
+        Ok(Self {
+            with_clause,
             compound,
             core,
             order_by,
             limit,
             offset,
-        }
+        })
+    }
+}
+
+impl Parser for WithClause {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
+
+        // 解析 RECURSIVE（可选）
+        let (recursive, pair) = match pair.as_rule() {
+            Rule::recursive => (true, inner.expect()?),
+            _ => (false, pair),
+        };
+
+        // 解析公用表表达式列表
+        let ctes = std::iter::once(pair)
+            .chain(inner)
+            .map(CommonTableExpr::parse)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { recursive, ctes })
+    }
+}
+
+impl Parser for CommonTableExpr {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
+
+        // 解析表名
+        let name = String::parse(pair)?;
+        let pair = inner.expect()?;
+
+        // 解析列名列表（可选）
+        let (columns, pair) = match pair.as_rule() {
+            Rule::idents => {
+                let cols = pair
+                    .into_inner()
+                    .map(String::parse)
+                    .collect::<Result<_, _>>()?;
+                (cols, inner.expect()?)
+            }
+            _ => (vec![], pair),
+        };
+
+        // 解析 [NOT] MATERIALIZED（可选）
+        let (materialized, pair) = match pair.as_rule() {
+            Rule::materialized => {
+                let not = Children::new(pair).next().is_some();
+                (Some(!not), inner.expect()?)
+            }
+            _ => (None, pair),
+        };
+
+        // 解析子查询
+        let select = Select::parse(pair)?;
+
+        Ok(Self {
+            name,
+            columns,
+            materialized,
+            select,
+        })
     }
 }
 
 impl Parser for SelectCore {
-    fn parse(pair: Pair<Rule>) -> Self {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
         match pair.as_rule() {
             Rule::select_core1 => {
-                let mut inner = pair.into_inner();
-                let pair = inner.next().unwrap();
+                let mut inner = Children::new(pair);
+                let pair = inner.expect()?;
 
                 // 解析 distinct（可选）
                 let (is_distinct, pair) = match pair.as_rule() {
-                    Rule::distinct => (true, inner.next().unwrap()),
-                    Rule::all => (false, inner.next().unwrap()),
+                    Rule::distinct => (true, inner.expect()?),
+                    Rule::all => (false, inner.expect()?),
                     _ => (false, pair),
                 };
 
                 // 解析结果列
-                let columns: Vec<_> = pair
+                let columns = pair
                     .into_inner()
-                    .map(|col_pair| ResultColumn::parse(col_pair))
-                    .collect();
+                    .map(ResultColumn::parse)
+                    .collect::<Result<_, _>>()?;
                 let pair = inner.next();
 
                 // 解析 FROM 子句（可选）
                 let (from_clause, pair) = match pair {
                     Some(pair) if pair.as_rule() == Rule::from_clause => {
-                        (Some(FromClause::parse(pair)), inner.next())
+                        (Some(FromClause::parse(pair)?), inner.next())
                     }
                     _ => (None, pair),
                 };
@@ -104,7 +196,7 @@ impl Parser for SelectCore {
                 // 解析 WHERE 子句（可选）
                 let (where_clause, pair) = match pair {
                     Some(pair) if pair.as_rule() == Rule::expr => {
-                        (Some(Expr::parse(pair)), inner.next())
+                        (Some(Expr::parse(pair)?), inner.next())
                     }
                     _ => (None, pair),
                 };
@@ -112,85 +204,103 @@ impl Parser for SelectCore {
                 // 解析 GROUP BY 子句（可选）
                 let (group_by, pair) = match pair {
                     Some(pair) if pair.as_rule() == Rule::exprs => {
-                        let group_exprs: Vec<Expr> = pair
+                        let group_exprs = pair
                             .into_inner()
-                            .map(|expr_pair| Expr::parse(expr_pair))
-                            .collect();
+                            .map(Expr::parse)
+                            .collect::<Result<_, _>>()?;
                         (group_exprs, inner.next())
                     }
                     _ => (vec![], pair),
                 };
 
                 // 解析 HAVING 子句（可选）
-                let having = pair.map(|p| Expr::parse(p));
+                let (having, pair) = match pair {
+                    Some(pair) if pair.as_rule() == Rule::expr => {
+                        (Some(Expr::parse(pair)?), inner.next())
+                    }
+                    _ => (None, pair),
+                };
 
-                Self::Query {
+                // 解析 WINDOW 子句（可选）
+                let window = match pair {
+                    Some(pair) => pair
+                        .into_inner()
+                        .map(parse_named_window)
+                        .collect::<Result<_, _>>()?,
+                    None => vec![],
+                };
+
+                Ok(Self::Query {
                     is_distinct,
                     columns,
                     from_clause,
                     where_clause,
                     group_by,
                     having,
-                }
+                    window,
+                })
             }
             Rule::select_core2 => {
-                let mut inner = pair.into_inner();
-                let pair = inner.next().unwrap();
-
-                let values = pair
-                    .into_inner()
-                    .map(|exprs| {
-                        exprs
-                            .into_inner()
-                            .map(|expr_pair| Expr::parse(expr_pair))
-                            .collect()
-                    })
-                    .collect();
-                Self::Values(values)
+                let pair = Children::new(pair).expect()?;
+                Ok(Self::Values(parse_values(pair)?))
             }
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[Rule::select_core1, Rule::select_core2],
+            )),
         }
     }
 }
 
 impl Parser for Insert {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
+
+        // 解析 WITH 子句（可选）
+        let (with_clause, pair) = match pair.as_rule() {
+            Rule::with_clause => (Some(WithClause::parse(pair)?), inner.expect()?),
+            _ => (None, pair),
+        };
 
         // 解析 insert 头部
         let header = match pair.as_rule() {
             Rule::insert_header1 => {
-                let conflict = pair
-                    .into_inner()
-                    .next()
-                    .map_or(ConflictResolution::Abort, |p| ConflictResolution::parse(p));
+                let conflict = match Children::new(pair).next() {
+                    Some(p) => ConflictResolution::parse(p)?,
+                    None => ConflictResolution::Abort,
+                };
 
                 InsertHeader::Insert(conflict)
             }
             Rule::insert_header2 => InsertHeader::Replace,
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[Rule::insert_header1, Rule::insert_header2],
+                ));
+            }
         };
-        let pair = inner.next().unwrap();
+        let pair = inner.expect()?;
 
         // 解析表名
-        let schema_table = SchemaObject::parse(pair);
-        let pair = inner.next().unwrap();
+        let schema_table = SchemaObject::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析别名（可选）
         let (alias, pair) = match pair.as_rule() {
-            Rule::ident => (Some(String::parse(pair)), inner.next().unwrap()),
+            Rule::ident => (Some(String::parse(pair)?), inner.expect()?),
             _ => (None, pair),
         };
 
         // 解析列名列表（可选）
         let (columns, pair) = match pair.as_rule() {
             Rule::idents => {
-                let cols: Vec<_> = pair
+                let cols = pair
                     .into_inner()
-                    .map(|ident| String::parse(ident))
-                    .collect();
-                (cols, inner.next().unwrap())
+                    .map(String::parse)
+                    .collect::<Result<_, _>>()?;
+                (cols, inner.expect()?)
             }
             _ => (vec![], pair),
         };
@@ -198,29 +308,26 @@ impl Parser for Insert {
         // 解析插入值
         let values = match pair.as_rule() {
             Rule::insert_body1 => {
-                let mut inner = pair.into_inner();
-                let pair = inner.next().unwrap();
+                let mut inner = Children::new(pair);
+                let pair = inner.expect()?;
 
                 // 解析 VALUES 子句
-                let values = pair
-                    .into_inner()
-                    .map(|exprs| exprs.into_inner().map(|expr| Expr::parse(expr)).collect())
-                    .collect();
+                let values = parse_values(pair)?;
 
                 // 解析 UPSERT 子句（可选）
-                let upsert = inner.next().map(|p| UpsertSubClause::parse(p));
+                let upsert = inner.next().map(UpsertSubClause::parse).transpose()?;
 
                 InsertValues::Values { values, upsert }
             }
             Rule::insert_body2 => {
-                let mut inner = pair.into_inner();
-                let pair = inner.next().unwrap();
+                let mut inner = Children::new(pair);
+                let pair = inner.expect()?;
 
                 // 解析 SELECT 语句
-                let select = Select::parse(pair);
+                let select = Select::parse(pair)?;
 
                 // 解析 UPSERT 子句（可选）
-                let upsert = inner.next().map(|p| UpsertSubClause::parse(p));
+                let upsert = inner.next().map(UpsertSubClause::parse).transpose()?;
 
                 InsertValues::Select {
                     select: Box::new(select),
@@ -228,188 +335,210 @@ impl Parser for Insert {
                 }
             }
             Rule::insert_body3 => InsertValues::Default,
-            rule => unreachable!("Unexpected rule: {:?}", rule),
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[Rule::insert_body1, Rule::insert_body2, Rule::insert_body3],
+                ));
+            }
         };
         let pair = inner.next();
 
         // 解析 RETURNING 子句（可选）
-        let return_clause: Vec<_> = pair.map_or(vec![], |pair| {
-            pair.into_inner()
-                .map(|pair| match pair.as_rule() {
-                    Rule::return_sub_clause1 => ReturnSubClause::Star,
-                    Rule::return_sub_clause2 => {
-                        let mut inner = pair.into_inner();
-                        let expr = Expr::parse(inner.next().unwrap());
-                        let alias = inner.next().map(|ident| String::parse(ident));
-
-                        ReturnSubClause::Expr(expr, alias)
-                    }
-                    rule => unreachable!("Unexpected rule: {:?}", rule),
-                })
-                .collect()
-        });
+        let return_clause = parse_return_clause(pair)?;
 
-        Self {
+        Ok(Self {
+            with_clause,
             header,
             schema_table,
             alias,
             columns,
             values,
             return_clause,
-        }
+        })
     }
 }
 
 impl Parser for Update {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
+
+        // 解析 WITH 子句（可选）
+        let (with_clause, pair) = match pair.as_rule() {
+            Rule::with_clause => (Some(WithClause::parse(pair)?), inner.expect()?),
+            _ => (None, pair),
+        };
 
         // 解析冲突解决方案（可选）
         let (conflict, pair) = match pair.as_rule() {
-            Rule::conflict_resolution => (ConflictResolution::parse(pair), inner.next().unwrap()),
+            Rule::conflict_resolution => (ConflictResolution::parse(pair)?, inner.expect()?),
             _ => (ConflictResolution::Abort, pair),
         };
 
         // 解析表名
-        let qualified_table = QualifiedTable::parse(pair);
-        let pair = inner.next().unwrap();
+        let qualified_table = QualifiedTable::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析赋值列表
-        let set_clause: Vec<SetSubClause> = pair
+        let set_clause = pair
             .into_inner()
-            .map(|assignment| SetSubClause::parse(assignment))
-            .collect();
+            .map(SetSubClause::parse)
+            .collect::<Result<_, _>>()?;
         let pair = inner.next();
 
         // 解析 FROM 子句（可选）
         let (from_clause, pair) = match pair {
             Some(p) if p.as_rule() == Rule::from_clause => {
-                (Some(FromClause::parse(p)), inner.next())
+                (Some(FromClause::parse(p)?), inner.next())
             }
             _ => (None, pair),
         };
 
         // 解析 WHERE 子句（可选）
         let (where_clause, pair) = match pair {
-            Some(p) if p.as_rule() == Rule::expr => (Some(Expr::parse(p)), inner.next()),
+            Some(p) if p.as_rule() == Rule::expr => (Some(Expr::parse(p)?), inner.next()),
             _ => (None, pair),
         };
 
         // 解析 RETURNING 子句（可选）
-        let return_clause: Vec<_> = pair.map_or(vec![], |pair| {
-            pair.into_inner()
-                .map(|pair| match pair.as_rule() {
-                    Rule::return_sub_clause1 => ReturnSubClause::Star,
-                    Rule::return_sub_clause2 => {
-                        let mut inner = pair.into_inner();
-                        let expr = Expr::parse(inner.next().unwrap());
-                        let alias = inner.next().map(|ident| String::parse(ident));
-
-                        ReturnSubClause::Expr(expr, alias)
-                    }
-                    rule => unreachable!("Unexpected rule: {:?}", rule),
-                })
-                .collect()
-        });
+        let return_clause = parse_return_clause(pair)?;
 
-        Self {
+        Ok(Self {
+            with_clause,
             conflict,
             qualified_table,
             set_clause,
             from_clause,
             where_clause,
             return_clause,
-        }
+        })
     }
 }
 
 impl Parser for Delete {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
+
+        // 解析 WITH 子句（可选）
+        let (with_clause, pair) = match pair.as_rule() {
+            Rule::with_clause => (Some(WithClause::parse(pair)?), inner.expect()?),
+            _ => (None, pair),
+        };
 
         // 解析表名
-        let qualified_table = QualifiedTable::parse(pair);
+        let qualified_table = QualifiedTable::parse(pair)?;
         let pair = inner.next();
 
         // 解析 WHERE 子句（可选）
         let (where_clause, pair) = match pair {
-            Some(p) if p.as_rule() == Rule::expr => (Some(Expr::parse(p)), inner.next()),
+            Some(p) if p.as_rule() == Rule::expr => (Some(Expr::parse(p)?), inner.next()),
             _ => (None, pair),
         };
 
         // 解析 RETURNING 子句（可选）
-        let return_clause: Vec<_> = pair.map_or(vec![], |pair| {
-            pair.into_inner()
-                .map(|pair| match pair.as_rule() {
-                    Rule::return_sub_clause1 => ReturnSubClause::Star,
-                    Rule::return_sub_clause2 => {
-                        let mut inner = pair.into_inner();
-                        let expr = Expr::parse(inner.next().unwrap());
-                        let alias = inner.next().map(|ident| String::parse(ident));
-
-                        ReturnSubClause::Expr(expr, alias)
-                    }
-                    rule => panic!("Unexpected rule: {:?}", rule),
-                })
-                .collect()
-        });
+        let return_clause = parse_return_clause(pair)?;
 
-        Self {
+        Ok(Self {
+            with_clause,
             qualified_table,
             where_clause,
             return_clause,
-        }
+        })
     }
 }
 
+/// 解析 VALUES 列表
+fn parse_values(pair: Pair<Rule>) -> Result<Vec<Vec<Expr>>, ParseError> {
+    pair.into_inner()
+        .map(|exprs| exprs.into_inner().map(Expr::parse).collect())
+        .collect()
+}
+
+/// 解析 WINDOW 子句中的命名窗口
+fn parse_named_window(pair: Pair<Rule>) -> Result<(String, WindowDefn), ParseError> {
+    let mut inner = Children::new(pair);
+    let name = String::parse(inner.expect()?)?;
+    let defn = WindowDefn::parse(inner.expect()?)?;
+
+    Ok((name, defn))
+}
+
+/// 解析 RETURNING 子句（可选）
+fn parse_return_clause(pair: Option<Pair<Rule>>) -> Result<Vec<ReturnSubClause>, ParseError> {
+    let Some(pair) = pair else {
+        return Ok(vec![]);
+    };
+
+    pair.into_inner()
+        .map(|pair| match pair.as_rule() {
+            Rule::return_sub_clause1 => Ok(ReturnSubClause::Star),
+            Rule::return_sub_clause2 => {
+                let mut inner = Children::new(pair);
+                let expr = Expr::parse(inner.expect()?)?;
+                let alias = inner.next().map(String::parse).transpose()?;
+
+                Ok(ReturnSubClause::Expr(expr, alias))
+            }
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[Rule::return_sub_clause1, Rule::return_sub_clause2],
+            )),
+        })
+        .collect()
+}
+
 impl Parser for ResultColumn {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
 
         match pair.as_rule() {
             Rule::result_column1 => {
-                let mut inner = pair.into_inner();
-                let expr = Expr::parse(inner.next().unwrap());
-                let alias = inner.next().map(|ident| String::parse(ident));
-                Self::Expr(expr, alias)
+                let mut inner = Children::new(pair);
+                let expr = Expr::parse(inner.expect()?)?;
+                let alias = inner.next().map(String::parse).transpose()?;
+                Ok(Self::Expr(expr, alias))
             }
-            Rule::result_column2 => Self::Star,
-            rule => panic!("Unexpected rule: {:?}", rule),
+            Rule::result_column2 => Ok(Self::Star),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[Rule::result_column1, Rule::result_column2],
+            )),
         }
     }
 }
 
 impl Parser for FromClause {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
 
         match pair.as_rule() {
             Rule::from_clause1 => {
-                let qualified_tables = pair
+                let tables = pair
                     .into_inner()
-                    .map(|table_pair| QualifiedTable::parse(table_pair))
-                    .collect();
-                FromClause::TableOrQuerys(qualified_tables)
+                    .map(TableOrSubquery::parse)
+                    .collect::<Result<_, _>>()?;
+                Ok(FromClause::TableOrQuerys(tables))
             }
-            Rule::from_clause2 => {
-                FromClause::Join(JoinClause::parse(pair.into_inner().next().unwrap()))
-            }
-            rule => panic!("Unexpected rule: {:?}", rule),
+            Rule::from_clause2 => Ok(FromClause::Join(JoinClause::parse(
+                Children::new(pair).expect()?,
+            )?)),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[Rule::from_clause1, Rule::from_clause2],
+            )),
         }
     }
 }
 
 impl Parser for OrderingTerm {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析表达式
-        let expr = Expr::parse(pair);
+        let expr = Expr::parse(pair)?;
         let pair = inner.next();
 
         // 解析排序方式（可选）
@@ -426,185 +555,242 @@ impl Parser for OrderingTerm {
             _ => true, // 默认 NULLS FIRST
         };
 
-        Self {
+        Ok(Self {
             expr,
             asc,
             nulls_first,
-        }
+        })
     }
 }
 
 impl Parser for SetSubClause {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析列名列表
         let (columns, pair) = match pair.as_rule() {
             Rule::idents => {
-                let cols: Vec<_> = pair
+                let cols = pair
                     .into_inner()
-                    .map(|ident| String::parse(ident))
-                    .collect();
-                (cols, inner.next().unwrap())
+                    .map(String::parse)
+                    .collect::<Result<_, _>>()?;
+                (cols, inner.expect()?)
             }
-            Rule::ident => (vec![String::parse(pair)], inner.next().unwrap()),
-            rule => unreachable!("Unexpected rule: {:?}", rule),
+            Rule::ident => (vec![String::parse(pair)?], inner.expect()?),
+            _ => return Err(ParseError::unexpected(&pair, &[Rule::idents, Rule::ident])),
         };
 
         // 解析赋值表达式
-        let value = Expr::parse(pair);
+        let value = Expr::parse(pair)?;
 
-        Self { columns, value }
+        Ok(Self { columns, value })
     }
 }
 
 impl Parser for JoinClause {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
 
-        // 解析连接操作符
-        let qualified_table = QualifiedTable::parse(inner.next().unwrap());
+        // 解析首个表或子查询
+        let table_or_subquery = TableOrSubquery::parse(inner.expect()?)?;
 
         // 解析 JOIN SUB CLAUSE 列表
-        let joins: Vec<_> = inner
-            .map(|sub_clause_pair| JoinSubClause::parse(sub_clause_pair))
-            .collect();
+        let joins = inner.map(JoinSubClause::parse).collect::<Result<_, _>>()?;
 
-        Self {
-            table_or_subquery: qualified_table,
+        Ok(Self {
+            table_or_subquery,
             joins,
-        }
+        })
     }
 }
 
 impl Parser for JoinSubClause {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析连接符
-        let join_operator = JoinOperator::parse(pair);
-        let pair = inner.next().unwrap();
+        let join_operator = JoinOperator::parse(pair)?;
+        let pair = inner.expect()?;
 
         // 解析连接表
-        let qualified_table = QualifiedTable::parse(pair);
+        let table_or_subquery = TableOrSubquery::parse(pair)?;
         let pair = inner.next();
 
         // 解析连接约束（可选）
-        let join_constraint = pair.map(|p| JoinConstraint::parse(p));
+        let join_constraint = pair.map(JoinConstraint::parse).transpose()?;
 
-        Self {
+        Ok(Self {
             operator: join_operator,
-            table_or_subquery: qualified_table,
+            table_or_subquery,
             constraint: join_constraint,
-        }
+        })
     }
 }
 
 impl Parser for JoinOperator {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
         match pair.as_rule() {
-            Rule::join_operator1 => Self::Comma,
-            Rule::join_operator2 => Self::Cross,
+            Rule::join_operator1 => Ok(Self::Comma),
+            Rule::join_operator2 => Ok(Self::Cross),
             Rule::join_operator3 => {
-                let mut inner = pair.into_inner();
-                let pair = inner.next().unwrap();
+                let mut inner = Children::new(pair);
+                let pair = inner.expect()?;
 
                 // 解析是否为 NATURAL 连接（可选）
                 let (natural, pair) = match pair.as_rule() {
-                    Rule::natural => (true, inner.next().unwrap()),
+                    Rule::natural => (true, inner.expect()?),
                     _ => (false, pair),
                 };
 
                 match pair.as_rule() {
-                    Rule::join_operator31 => Self::Inner(natural),
-                    Rule::left => Self::Outer(natural, OuterJoinType::Left),
-                    Rule::right => Self::Outer(natural, OuterJoinType::Right),
-                    Rule::full => Self::Outer(natural, OuterJoinType::Full),
-                    rule => panic!("Unexpected rule: {:?}", rule),
+                    Rule::join_operator31 => Ok(Self::Inner(natural)),
+                    Rule::left => Ok(Self::Outer(natural, OuterJoinType::Left)),
+                    Rule::right => Ok(Self::Outer(natural, OuterJoinType::Right)),
+                    Rule::full => Ok(Self::Outer(natural, OuterJoinType::Full)),
+                    _ => Err(ParseError::unexpected(
+                        &pair,
+                        &[Rule::join_operator31, Rule::left, Rule::right, Rule::full],
+                    )),
                 }
             }
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[
+                    Rule::join_operator1,
+                    Rule::join_operator2,
+                    Rule::join_operator3,
+                ],
+            )),
         }
     }
 }
 
 impl Parser for JoinConstraint {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
         match pair.as_rule() {
-            Rule::expr => Self::Expr(Expr::parse(pair)),
+            Rule::expr => Ok(Self::Expr(Expr::parse(pair)?)),
             Rule::idents => {
                 let columns = pair
                     .into_inner()
-                    .map(|ident| String::parse(ident))
-                    .collect();
-                Self::Using(columns)
+                    .map(String::parse)
+                    .collect::<Result<_, _>>()?;
+                Ok(Self::Using(columns))
             }
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => Err(ParseError::unexpected(&pair, &[Rule::expr, Rule::idents])),
+        }
+    }
+}
+
+impl Parser for TableOrSubquery {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
+
+        match pair.as_rule() {
+            Rule::qualified_table => Ok(Self::Table(QualifiedTable::parse(pair)?)),
+            Rule::table_or_subquery1 => {
+                let mut inner = Children::new(pair);
+                let schema_function = SchemaObject::parse(inner.expect()?)?;
+                let pair = inner.next();
+
+                // 解析函数参数（可选）
+                let (args, pair) = match pair {
+                    Some(p) if p.as_rule() == Rule::exprs => (
+                        p.into_inner().map(Expr::parse).collect::<Result<_, _>>()?,
+                        inner.next(),
+                    ),
+                    _ => (vec![], pair),
+                };
+
+                // 解析别名（可选）
+                let alias = pair.map(String::parse).transpose()?;
+
+                Ok(Self::TableFunction {
+                    schema_function,
+                    args,
+                    alias,
+                })
+            }
+            Rule::table_or_subquery2 => {
+                let mut inner = Children::new(pair);
+                let select = Box::new(Select::parse(inner.expect()?)?);
+                let alias = inner.next().map(String::parse).transpose()?;
+
+                Ok(Self::Subquery { select, alias })
+            }
+            Rule::table_or_subquery3 => Ok(Self::Join(Box::new(JoinClause::parse(
+                Children::new(pair).expect()?,
+            )?))),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[
+                    Rule::table_or_subquery1,
+                    Rule::qualified_table,
+                    Rule::table_or_subquery2,
+                    Rule::table_or_subquery3,
+                ],
+            )),
         }
     }
 }
 
 impl Parser for QualifiedTable {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
-        let schema_table = SchemaObject::parse(pair);
+        let schema_table = SchemaObject::parse(pair)?;
         let pair = inner.next();
 
         // 解析别名（可选）
         let (alias, pair) = match pair {
-            Some(p) if p.as_rule() == Rule::ident => (Some(String::parse(p)), inner.next()),
+            Some(p) if p.as_rule() == Rule::ident => (Some(String::parse(p)?), inner.next()),
             _ => (None, pair),
         };
 
         // 解析索引信息（可选）
         let indexed = match pair {
             Some(pair) if pair.as_rule() == Rule::indexed1 => {
-                let mut inner = pair.into_inner();
-                let index = String::parse(inner.next().unwrap());
+                let index = String::parse(Children::new(pair).expect()?)?;
                 Some(Indexed::By(index))
             }
             Some(pair) if pair.as_rule() == Rule::indexed2 => Some(Indexed::NotIndexed),
             _ => None,
         };
 
-        Self {
+        Ok(Self {
             schema_table,
             alias,
             indexed,
-        }
+        })
     }
 }
 
 impl Parser for UpsertSubClause {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
         // 解析冲突列（可选）
         let (indexed_cols, where_clause, pair) = match pair.as_rule() {
             Rule::conflict_columns => {
-                let mut inside = pair.into_inner();
-                let pair = inside.next().unwrap();
+                let mut inside = Children::new(pair);
+                let pair = inside.expect()?;
 
                 // 解析列名列表
-                let cols: Vec<_> = pair
+                let cols = pair
                     .into_inner()
-                    .map(|col| IndexedColumn::parse(col))
-                    .collect();
+                    .map(IndexedColumn::parse)
+                    .collect::<Result<_, _>>()?;
                 let pair = inside.next();
 
                 // 解析 WHERE 子句（可选）
-                let where_clause = pair.map(|p| Expr::parse(p));
+                let where_clause = pair.map(Expr::parse).transpose()?;
 
-                (cols, where_clause, inner.next().unwrap())
+                (cols, where_clause, inner.expect()?)
             }
             _ => (vec![], None, pair),
         };
@@ -613,30 +799,35 @@ impl Parser for UpsertSubClause {
         let upsert_type = match pair.as_rule() {
             Rule::upsert_sub_clause1 => UpsertType::Nothing,
             Rule::upsert_sub_clause2 => {
-                let mut inner = pair.into_inner();
-                let pair = inner.next().unwrap();
+                let mut inner = Children::new(pair);
+                let pair = inner.expect()?;
 
                 // 解析赋值列表
-                let set_clause: Vec<SetSubClause> = pair
+                let set_clause = pair
                     .into_inner()
-                    .map(|assignment| SetSubClause::parse(assignment))
-                    .collect();
+                    .map(SetSubClause::parse)
+                    .collect::<Result<_, _>>()?;
 
                 // 解析 WHERE 子句（可选）
-                let where_clause = inner.next().map(|p| Expr::parse(p));
+                let where_clause = inner.next().map(Expr::parse).transpose()?;
 
                 UpsertType::Update {
                     set_clause,
                     where_clause,
                 }
             }
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[Rule::upsert_sub_clause1, Rule::upsert_sub_clause2],
+                ));
+            }
         };
 
-        Self {
+        Ok(Self {
             indexed_cols,
             where_clause,
             upsert_type,
-        }
+        })
     }
 }
@@ -1,40 +1,142 @@
+mod admin;
 mod ddl;
 mod dml;
 mod tcl;
 
-use crate::{Rule, ast::*};
+use crate::{ParseError, Rule, ast::*};
 use pest::{
-    iterators::Pair,
+    Span,
+    iterators::{Pair, Pairs},
     pratt_parser::{Assoc, Op, PrattParser},
 };
 
-pub trait Parser {
-    fn parse(pair: Pair<Rule>) -> Self;
+pub trait Parser: Sized {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError>;
+}
+
+/// 子节点游标，在子节点缺失时返回指向父节点的错误
+pub(crate) struct Children<'i> {
+    rule: Rule,
+    span: Span<'i>,
+    pairs: Pairs<'i, Rule>,
+}
+
+impl<'i> Children<'i> {
+    pub(crate) fn new(pair: Pair<'i, Rule>) -> Self {
+        Self {
+            rule: pair.as_rule(),
+            span: pair.as_span(),
+            pairs: pair.into_inner(),
+        }
+    }
+
+    /// 取出下一个必需的子节点
+    pub(crate) fn expect(&mut self) -> Result<Pair<'i, Rule>, ParseError> {
+        self.pairs
+            .next()
+            .ok_or_else(|| ParseError::missing(self.rule, self.span))
+    }
+}
+
+impl<'i> Iterator for Children<'i> {
+    type Item = Pair<'i, Rule>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pairs.next()
+    }
+}
+
+impl ExactSizeIterator for Children<'_> {
+    fn len(&self) -> usize {
+        self.pairs.len()
+    }
 }
 
 impl Parser for Stmt {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let pair = pair.into_inner().next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
 
+        // 解析 EXPLAIN 前缀（可选）
         match pair.as_rule() {
-            Rule::select => Stmt::Select(Select::parse(pair)),
-            Rule::insert => Stmt::Insert(Insert::parse(pair)),
-            Rule::update => Stmt::Update(Update::parse(pair)),
-            Rule::delete => Stmt::Delete(Delete::parse(pair)),
-            Rule::create_table => Stmt::CreateTable(CreateTable::parse(pair)),
-            Rule::create_index => Stmt::CreateIndex(CreateIndex::parse(pair)),
-            Rule::create_view => Stmt::CreateView(CreateView::parse(pair)),
-            Rule::create_trigger => Stmt::CreateTrigger(CreateTrigger::parse(pair)),
-            Rule::alter_table => Stmt::AlterTable(AlterTable::parse(pair)),
-            Rule::drop_table => Stmt::DropTable(DropTable::parse(pair)),
-            Rule::drop_index => Stmt::DropIndex(DropIndex::parse(pair)),
-            Rule::drop_view => Stmt::DropView(DropView::parse(pair)),
-            Rule::drop_trigger => Stmt::DropTrigger(DropTrigger::parse(pair)),
-            _ => unreachable!("Unexpected statement rule: {:?}", pair.as_rule()),
+            Rule::explain => {
+                let query_plan = Children::new(pair).next().is_some();
+                let stmt = parse_stmt_body(inner.expect()?)?;
+                Ok(Stmt::Explain {
+                    query_plan,
+                    stmt: Box::new(stmt),
+                })
+            }
+            _ => parse_stmt_body(pair),
         }
     }
 }
 
+/// 按语句类型分派解析
+fn parse_stmt_body(pair: Pair<Rule>) -> Result<Stmt, ParseError> {
+    let rule = pair.as_rule();
+
+    let stmt = match rule {
+        Rule::select => Select::parse(pair).map(Stmt::Select),
+        Rule::insert => Insert::parse(pair).map(Stmt::Insert),
+        Rule::update => Update::parse(pair).map(Stmt::Update),
+        Rule::delete => Delete::parse(pair).map(Stmt::Delete),
+        Rule::create_table => CreateTable::parse(pair).map(Stmt::CreateTable),
+        Rule::create_virtual_table => CreateVirtualTable::parse(pair).map(Stmt::CreateVirtualTable),
+        Rule::create_index => CreateIndex::parse(pair).map(Stmt::CreateIndex),
+        Rule::create_view => CreateView::parse(pair).map(Stmt::CreateView),
+        Rule::create_trigger => CreateTrigger::parse(pair).map(Stmt::CreateTrigger),
+        Rule::alter_table => AlterTable::parse(pair).map(Stmt::AlterTable),
+        Rule::drop_table => DropTable::parse(pair).map(Stmt::DropTable),
+        Rule::drop_index => DropIndex::parse(pair).map(Stmt::DropIndex),
+        Rule::drop_view => DropView::parse(pair).map(Stmt::DropView),
+        Rule::drop_trigger => DropTrigger::parse(pair).map(Stmt::DropTrigger),
+        Rule::begin => Begin::parse(pair).map(Stmt::Begin),
+        Rule::commit => Commit::parse(pair).map(Stmt::Commit),
+        Rule::rollback => Rollback::parse(pair).map(Stmt::Rollback),
+        Rule::savepoint => Savepoint::parse(pair).map(Stmt::Savepoint),
+        Rule::release => Release::parse(pair).map(Stmt::Release),
+        Rule::pragma => Pragma::parse(pair).map(Stmt::Pragma),
+        Rule::attach => Attach::parse(pair).map(Stmt::Attach),
+        Rule::detach => Detach::parse(pair).map(Stmt::Detach),
+        Rule::vacuum => Vacuum::parse(pair).map(Stmt::Vacuum),
+        Rule::analyze => Analyze::parse(pair).map(Stmt::Analyze),
+        Rule::reindex => Reindex::parse(pair).map(Stmt::Reindex),
+        _ => Err(ParseError::unexpected(
+            &pair,
+            &[
+                Rule::select,
+                Rule::insert,
+                Rule::update,
+                Rule::delete,
+                Rule::create_table,
+                Rule::create_virtual_table,
+                Rule::create_index,
+                Rule::create_view,
+                Rule::create_trigger,
+                Rule::alter_table,
+                Rule::drop_table,
+                Rule::drop_index,
+                Rule::drop_view,
+                Rule::drop_trigger,
+                Rule::begin,
+                Rule::commit,
+                Rule::rollback,
+                Rule::savepoint,
+                Rule::release,
+                Rule::pragma,
+                Rule::attach,
+                Rule::detach,
+                Rule::vacuum,
+                Rule::analyze,
+                Rule::reindex,
+            ],
+        )),
+    };
+
+    stmt.map_err(|e| e.in_stmt(rule))
+}
+
 lazy_static::lazy_static! {
     static ref PRATT_PARSER: PrattParser<Rule> = {
         use Rule::*;
@@ -48,10 +150,17 @@ lazy_static::lazy_static! {
             .op(Op::infix(logical_and, Left))
             // NOT
             .op(Op::prefix(logical_not))
-            // IS, IS NOT
-            .op(Op::infix(is_not, Left) | Op::infix(is, Left))
-            // =, !=
-            .op(Op::infix(eq, Left) | Op::infix(ne, Left))
+            // =, !=, IS, IS NOT, IS [NOT] DISTINCT FROM, ISNULL, NOTNULL, IN, LIKE, GLOB, REGEXP, MATCH
+            .op(Op::infix(eq, Left)
+                | Op::infix(ne, Left)
+                | Op::infix(is_distinct_from, Left)
+                | Op::infix(is_not_distinct_from, Left)
+                | Op::infix(is_not, Left)
+                | Op::infix(is, Left)
+                | Op::postfix(isnull)
+                | Op::postfix(notnull)
+                | Op::postfix(r#in)
+                | Op::postfix(pattern_match))
             // <, <=, >, >=
             .op(Op::infix(lt, Left) | Op::infix(le, Left) | Op::infix(gt, Left) | Op::infix(ge, Left))
             // &, |, >>, <<
@@ -62,45 +171,88 @@ lazy_static::lazy_static! {
             .op(Op::infix(mul, Left) | Op::infix(div, Left) | Op::infix(r#mod, Left))
             // ||
             .op(Op::infix(concat, Left))
+            // COLLATE
+            .op(Op::postfix(collate))
             // +, -, ~
             .op(Op::prefix(bitwise_not) | Op::prefix(positive) | Op::prefix(negative))
     };
 }
 
 impl Parser for Expr {
-    fn parse(pair: Pair<Rule>) -> Self {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
         let pairs = pair.into_inner();
 
         PRATT_PARSER
             .map_primary(|primary| match primary.as_rule() {
-                Rule::exprs => Self::ExprList(primary.into_inner().map(Self::parse).collect()),
-                Rule::literal => Self::Literal(Literal::parse(primary)),
+                Rule::exprs => Ok(Self::ExprList(
+                    primary
+                        .into_inner()
+                        .map(Self::parse)
+                        .collect::<Result<_, _>>()?,
+                )),
+                Rule::literal => Ok(Self::Literal(Literal::parse(primary)?)),
+                Rule::param => Ok(Self::Param(Param::parse(primary)?)),
                 Rule::qualified_column => {
-                    let mut inner = primary.into_inner();
+                    let mut inner = Children::new(primary);
 
                     // 解析模式名和表名
                     let (schema_name, table_name) = match inner.len() {
                         3 => {
-                            let schema_name = String::parse(inner.next().unwrap());
-                            let table_name = String::parse(inner.next().unwrap());
+                            let schema_name = String::parse(inner.expect()?)?;
+                            let table_name = String::parse(inner.expect()?)?;
                             (Some(schema_name), Some(table_name))
                         }
                         2 => {
-                            let table_name = String::parse(inner.next().unwrap());
+                            let table_name = String::parse(inner.expect()?)?;
                             (None, Some(table_name))
                         }
                         _ => (None, None),
                     };
 
                     // 解析列名
-                    let column_name = String::parse(inner.next().unwrap());
+                    let column_name = String::parse(inner.expect()?)?;
 
-                    Self::QualifiedColumn(schema_name, table_name, column_name)
+                    Ok(Self::QualifiedColumn(schema_name, table_name, column_name))
                 }
-                _ => unreachable!("Unexpected Rule: {:?}", primary),
+                Rule::exists_expr => {
+                    let mut inner = Children::new(primary);
+                    let pair = inner.expect()?;
+
+                    // 解析 NOT（可选）
+                    let (not, pair) = match pair.as_rule() {
+                        Rule::not => (true, inner.expect()?),
+                        _ => (false, pair),
+                    };
+
+                    let select = Box::new(Select::parse(pair)?);
+                    Ok(Self::Exists { not, select })
+                }
+                Rule::select => Ok(Self::Subquery(Box::new(Select::parse(primary)?))),
+                Rule::function_call => parse_function_call(primary),
+                Rule::case_expr => parse_case_expr(primary),
+                Rule::cast_expr => {
+                    let mut inner = Children::new(primary);
+                    let expr = Expr::parse(inner.expect()?)?;
+                    let type_name = TypeName::parse(inner.expect()?)?;
+                    Ok(Self::Cast(Box::new(expr), type_name))
+                }
+                _ => Err(ParseError::unexpected(
+                    &primary,
+                    &[
+                        Rule::literal,
+                        Rule::param,
+                        Rule::case_expr,
+                        Rule::cast_expr,
+                        Rule::exists_expr,
+                        Rule::select,
+                        Rule::function_call,
+                        Rule::qualified_column,
+                        Rule::exprs,
+                    ],
+                )),
             })
-            .map_infix(|lhs, op, rhs| {
-                let op = match op.as_rule() {
+            .map_infix(|lhs, op_pair, rhs| {
+                let op = match op_pair.as_rule() {
                     Rule::concat => BinaryOp::Concat,
                     Rule::mul => BinaryOp::Mul,
                     Rule::div => BinaryOp::Div,
@@ -119,12 +271,34 @@ impl Parser for Expr {
                     Rule::ne => BinaryOp::Ne,
                     Rule::is => BinaryOp::Is,
                     Rule::is_not => BinaryOp::IsNot,
+                    Rule::is_distinct_from => BinaryOp::IsDistinctFrom,
+                    Rule::is_not_distinct_from => BinaryOp::IsNotDistinctFrom,
                     Rule::logical_and => BinaryOp::LogicalAnd,
                     Rule::logical_or => BinaryOp::LogicalOr,
-                    rule => unreachable!("Expr::parse expected infix operation, found {:?}", rule),
+                    _ => return Err(ParseError::unexpected(&op_pair, &[])),
                 };
 
-                Self::Binary(Box::new(lhs), op, Box::new(rhs))
+                Ok(Self::Binary(Box::new(lhs?), op, Box::new(rhs?)))
+            })
+            .map_postfix(|lhs, op| match op.as_rule() {
+                Rule::r#in => parse_in(lhs?, op),
+                Rule::pattern_match => parse_pattern_match(lhs?, op),
+                Rule::isnull => Ok(Self::Unary(UnaryOp::IsNull, Box::new(lhs?))),
+                Rule::notnull => Ok(Self::Unary(UnaryOp::NotNull, Box::new(lhs?))),
+                Rule::collate => {
+                    let collation = String::parse(Children::new(op).expect()?)?;
+                    Ok(Self::Collate(Box::new(lhs?), collation))
+                }
+                _ => Err(ParseError::unexpected(
+                    &op,
+                    &[
+                        Rule::r#in,
+                        Rule::pattern_match,
+                        Rule::collate,
+                        Rule::isnull,
+                        Rule::notnull,
+                    ],
+                )),
             })
             .map_prefix(|op, rhs| {
                 let op = match op.as_rule() {
@@ -132,20 +306,340 @@ impl Parser for Expr {
                     Rule::positive => UnaryOp::Positive,
                     Rule::bitwise_not => UnaryOp::BitwiseNot,
                     Rule::logical_not => UnaryOp::LogicalNot,
-                    rule => unreachable!("Expr::parse expected prefix operation, found {:?}", rule),
+                    _ => return Err(ParseError::unexpected(&op, &[])),
                 };
-                Self::Unary(op, Box::new(rhs))
+                Ok(Self::Unary(op, Box::new(rhs?)))
             })
             .parse(pairs)
     }
 }
 
-impl Parser for Literal {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let pair = inner.next().unwrap();
+/// 解析 IN 运算符
+fn parse_in(expr: Expr, pair: Pair<Rule>) -> Result<Expr, ParseError> {
+    let mut inner = Children::new(pair);
+    let pair = inner.next();
+
+    // 解析 NOT（可选）
+    let (not, pair) = match pair {
+        Some(p) if p.as_rule() == Rule::not => (true, inner.next()),
+        _ => (false, pair),
+    };
+
+    // 解析右侧的值
+    let rhs = match pair {
+        Some(p) if p.as_rule() == Rule::select => InValues::Select(Box::new(Select::parse(p)?)),
+        Some(p) if p.as_rule() == Rule::exprs => {
+            InValues::List(p.into_inner().map(Expr::parse).collect::<Result<_, _>>()?)
+        }
+        Some(p) if p.as_rule() == Rule::schema_object => InValues::Table(SchemaObject::parse(p)?),
+        Some(p) => {
+            return Err(ParseError::unexpected(
+                &p,
+                &[Rule::select, Rule::exprs, Rule::schema_object],
+            ));
+        }
+        None => InValues::List(vec![]),
+    };
+
+    Ok(Expr::In {
+        expr: Box::new(expr),
+        not,
+        rhs,
+    })
+}
+
+/// 解析 LIKE、GLOB、REGEXP、MATCH 运算符
+fn parse_pattern_match(expr: Expr, pair: Pair<Rule>) -> Result<Expr, ParseError> {
+    let mut inner = Children::new(pair);
+    let pair = inner.expect()?;
+
+    // 解析 NOT（可选）
+    let (not, pair) = match pair.as_rule() {
+        Rule::not => (true, inner.expect()?),
+        _ => (false, pair),
+    };
+
+    let op = match pair.as_rule() {
+        Rule::like => LikeOp::Like,
+        Rule::glob => LikeOp::Glob,
+        Rule::regexp => LikeOp::Regexp,
+        Rule::r#match => LikeOp::Match,
+        _ => {
+            return Err(ParseError::unexpected(
+                &pair,
+                &[Rule::like, Rule::glob, Rule::regexp, Rule::r#match],
+            ));
+        }
+    };
+    let pattern = Expr::parse(inner.expect()?)?;
+
+    // 解析 ESCAPE 子句（可选）
+    let escape = match inner.next() {
+        Some(_) => Some(Box::new(Expr::parse(inner.expect()?)?)),
+        None => None,
+    };
+
+    Ok(Expr::Like {
+        expr: Box::new(expr),
+        op,
+        not,
+        pattern: Box::new(pattern),
+        escape,
+    })
+}
+
+/// 解析函数调用
+fn parse_function_call(pair: Pair<Rule>) -> Result<Expr, ParseError> {
+    let mut inner = Children::new(pair);
+
+    // 解析函数名
+    let pair = inner.expect()?;
+    let name = match pair.as_rule() {
+        Rule::function_name => pair.as_str().to_lowercase(),
+        _ => String::parse(pair)?,
+    };
+    let pair = inner.next();
+
+    // 解析 DISTINCT（可选）
+    let (distinct, pair) = match pair {
+        Some(p) if p.as_rule() == Rule::distinct => (true, inner.next()),
+        _ => (false, pair),
+    };
+
+    // 解析参数列表（可选）
+    let (args, pair) = match pair {
+        Some(p) if p.as_rule() == Rule::function_args1 => (FunctionArgs::Star, inner.next()),
+        Some(p) if p.as_rule() == Rule::exprs => {
+            let args = p.into_inner().map(Expr::parse).collect::<Result<_, _>>()?;
+            (FunctionArgs::List(args), inner.next())
+        }
+        _ => (FunctionArgs::List(vec![]), pair),
+    };
+
+    // 解析参数内的 ORDER BY（可选）
+    let (order_by, pair) = match pair {
+        Some(p) if p.as_rule() == Rule::ordering_terms => {
+            let terms = p
+                .into_inner()
+                .map(OrderingTerm::parse)
+                .collect::<Result<_, _>>()?;
+            (terms, inner.next())
+        }
+        _ => (vec![], pair),
+    };
+
+    // 解析 FILTER 子句（可选）
+    let (filter, pair) = match pair {
+        Some(p) if p.as_rule() == Rule::filter_clause => (
+            Some(Box::new(Expr::parse(Children::new(p).expect()?)?)),
+            inner.next(),
+        ),
+        _ => (None, pair),
+    };
+
+    // 解析 OVER 子句（可选）
+    let over = pair.map(Over::parse).transpose()?;
 
+    Ok(Expr::Function {
+        name,
+        distinct,
+        args,
+        filter,
+        order_by,
+        over,
+    })
+}
+
+impl Parser for Over {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
+        match pair.as_rule() {
+            Rule::window_defn => Ok(Self::Window(WindowDefn::parse(pair)?)),
+            Rule::ident => Ok(Self::Name(String::parse(pair)?)),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[Rule::window_defn, Rule::ident],
+            )),
+        }
+    }
+}
+
+impl Parser for WindowDefn {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.next();
+
+        // 解析基础窗口名（可选）
+        let (base, pair) = match pair {
+            Some(p) if p.as_rule() == Rule::ident => (Some(String::parse(p)?), inner.next()),
+            _ => (None, pair),
+        };
+
+        // 解析 PARTITION BY（可选）
+        let (partition_by, pair) = match pair {
+            Some(p) if p.as_rule() == Rule::exprs => (
+                p.into_inner().map(Expr::parse).collect::<Result<_, _>>()?,
+                inner.next(),
+            ),
+            _ => (vec![], pair),
+        };
+
+        // 解析 ORDER BY（可选）
+        let (order_by, pair) = match pair {
+            Some(p) if p.as_rule() == Rule::ordering_terms => (
+                p.into_inner()
+                    .map(OrderingTerm::parse)
+                    .collect::<Result<_, _>>()?,
+                inner.next(),
+            ),
+            _ => (vec![], pair),
+        };
+
+        // 解析窗口帧（可选）
+        let frame = pair.map(FrameSpec::parse).transpose()?;
+
+        Ok(Self {
+            base,
+            partition_by,
+            order_by,
+            frame,
+        })
+    }
+}
+
+impl Parser for FrameSpec {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let pair = inner.expect()?;
+
+        // 解析帧单位
+        let unit = match pair.as_rule() {
+            Rule::frame_range => FrameUnit::Range,
+            Rule::frame_rows => FrameUnit::Rows,
+            Rule::frame_groups => FrameUnit::Groups,
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[Rule::frame_range, Rule::frame_rows, Rule::frame_groups],
+                ));
+            }
+        };
+
+        // 解析帧起始边界
+        let start = FrameBound::parse(inner.expect()?)?;
+        let pair = inner.next();
+
+        // 解析帧结束边界（可选，默认为 CURRENT ROW）
+        let (end, pair) = match pair {
+            Some(p) if p.as_rule() == Rule::frame_end => (FrameBound::parse(p)?, inner.next()),
+            _ => (FrameBound::CurrentRow, pair),
+        };
+
+        // 解析 EXCLUDE（可选）
+        let exclude = match pair {
+            Some(p) => match p.as_rule() {
+                Rule::exclude_no_others => FrameExclude::NoOthers,
+                Rule::exclude_current_row => FrameExclude::CurrentRow,
+                Rule::exclude_group => FrameExclude::Group,
+                Rule::exclude_ties => FrameExclude::Ties,
+                _ => {
+                    return Err(ParseError::unexpected(
+                        &p,
+                        &[
+                            Rule::exclude_no_others,
+                            Rule::exclude_current_row,
+                            Rule::exclude_group,
+                            Rule::exclude_ties,
+                        ],
+                    ));
+                }
+            },
+            None => FrameExclude::NoOthers,
+        };
+
+        Ok(Self {
+            unit,
+            start,
+            end,
+            exclude,
+        })
+    }
+}
+
+impl Parser for FrameBound {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
         match pair.as_rule() {
+            Rule::frame_bound1 => Ok(Self::UnboundedPreceding),
+            Rule::frame_bound2 => Ok(Self::UnboundedFollowing),
+            Rule::frame_bound3 => Ok(Self::CurrentRow),
+            Rule::frame_bound4 => Ok(Self::Preceding(Box::new(Expr::parse(
+                Children::new(pair).expect()?,
+            )?))),
+            Rule::frame_bound5 => Ok(Self::Following(Box::new(Expr::parse(
+                Children::new(pair).expect()?,
+            )?))),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[
+                    Rule::frame_bound1,
+                    Rule::frame_bound2,
+                    Rule::frame_bound3,
+                    Rule::frame_bound4,
+                    Rule::frame_bound5,
+                ],
+            )),
+        }
+    }
+}
+
+/// 解析 CASE 表达式
+fn parse_case_expr(pair: Pair<Rule>) -> Result<Expr, ParseError> {
+    let mut inner = Children::new(pair);
+    let pair = inner.expect()?;
+
+    // 解析 CASE 之后的基础表达式（可选）
+    let (operand, pair) = match pair.as_rule() {
+        Rule::expr => (Some(Box::new(Expr::parse(pair)?)), inner.expect()?),
+        _ => (None, pair),
+    };
+
+    // 解析 WHEN ... THEN ... 分支
+    let mut when_then = vec![];
+    let mut else_expr = None;
+    for p in std::iter::once(pair).chain(inner) {
+        match p.as_rule() {
+            Rule::case_when => {
+                let mut inner = Children::new(p);
+                let when = Expr::parse(inner.expect()?)?;
+                let then = Expr::parse(inner.expect()?)?;
+                when_then.push((when, then));
+            }
+            Rule::case_else => {
+                let expr = Expr::parse(Children::new(p).expect()?)?;
+                else_expr = Some(Box::new(expr));
+            }
+            _ => {
+                return Err(ParseError::unexpected(
+                    &p,
+                    &[Rule::case_when, Rule::case_else],
+                ));
+            }
+        }
+    }
+
+    Ok(Expr::Case {
+        operand,
+        when_then,
+        else_expr,
+    })
+}
+
+impl Parser for Literal {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
+
+        let literal = match pair.as_rule() {
             Rule::double => Self::Double(pair.as_str().to_owned()),
             Rule::decimal => Self::Decimal(pair.as_str().to_owned()),
             Rule::integer => Self::Integer(pair.as_str().to_owned()),
@@ -160,58 +654,116 @@ impl Parser for Literal {
             Rule::null => Self::Null,
             Rule::r#true => Self::Bool(true),
             Rule::r#false => Self::Bool(false),
-            rule => panic!("Unexpected rule: {:?}", rule),
+            _ => {
+                return Err(ParseError::unexpected(
+                    &pair,
+                    &[
+                        Rule::r#true,
+                        Rule::r#false,
+                        Rule::null,
+                        Rule::double,
+                        Rule::decimal,
+                        Rule::integer,
+                        Rule::string,
+                        Rule::blob,
+                    ],
+                ));
+            }
+        };
+
+        Ok(literal)
+    }
+}
+
+impl Parser for Param {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        if pair.as_rule() != Rule::param {
+            return Err(ParseError::unexpected(&pair, &[Rule::param]));
+        }
+
+        let str = pair.as_str();
+        match str.split_at(1) {
+            ("?", "") => Ok(Self::Anonymous),
+            // 编号从 1 开始
+            ("?", number) => match number.parse() {
+                Ok(n) if n > 0 => Ok(Self::Numbered(n)),
+                _ => Err(ParseError::unexpected(&pair, &[])),
+            },
+            _ => Ok(Self::Named(str.to_owned())),
         }
     }
 }
 
 impl Parser for SchemaObject {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let first = inner.expect()?;
 
         // 解析模式名（可选）
-        let (schema_name, name) = match (inner.next(), inner.next()) {
-            (Some(schema_pair), Some(name_pair)) => {
-                let schema_name = String::parse(schema_pair);
-                let name = String::parse(name_pair);
-                (Some(schema_name), name)
-            }
-            (Some(name_pair), None) => {
-                let name = String::parse(name_pair);
-                (None, name)
-            }
-            _ => unreachable!("Unexpected schema object format"),
+        let (schema_name, name) = match inner.next() {
+            Some(name_pair) => (Some(String::parse(first)?), String::parse(name_pair)?),
+            None => (None, String::parse(first)?),
         };
 
-        Self { schema_name, name }
+        Ok(Self { schema_name, name })
+    }
+}
+
+/// 解析带符号的数字，符号并入字面量
+fn parse_signed_number(pair: Pair<Rule>) -> Result<Literal, ParseError> {
+    let mut inner = Children::new(pair);
+    let sign = inner.expect()?.as_str();
+    let number = inner.expect()?;
+    let text = format!("{sign}{}", number.as_str());
+
+    match number.as_rule() {
+        Rule::double => Ok(Literal::Double(text)),
+        Rule::decimal => Ok(Literal::Decimal(text)),
+        Rule::integer => Ok(Literal::Integer(text)),
+        _ => Err(ParseError::unexpected(
+            &number,
+            &[Rule::double, Rule::decimal, Rule::integer],
+        )),
     }
 }
 
 impl Parser for String {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let pair = pair.into_inner().next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
 
         match pair.as_rule() {
-            Rule::ident_inner => pair.as_str().to_lowercase(),
+            Rule::ident_inner => Ok(pair.as_str().to_lowercase()),
             Rule::quoted_ident => {
                 let str = pair.as_str();
-                str[1..str.len() - 1].to_owned()
+                Ok(str[1..str.len() - 1].to_owned())
             }
-            _ => unreachable!("Unexpected {:?}", pair),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[Rule::ident_inner, Rule::quoted_ident],
+            )),
         }
     }
 }
 
 impl Parser for ConflictResolution {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let pair = pair.into_inner().next().unwrap();
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let pair = Children::new(pair).expect()?;
         match pair.as_rule() {
-            Rule::abort => ConflictResolution::Abort,
-            Rule::fail => ConflictResolution::Fail,
-            Rule::ignore => ConflictResolution::Ignore,
-            Rule::replace => ConflictResolution::Replace,
-            Rule::rollback_kw => ConflictResolution::Rollback,
-            rule => unreachable!("Unexpected rule: {:?}", rule),
+            Rule::abort => Ok(ConflictResolution::Abort),
+            Rule::fail => Ok(ConflictResolution::Fail),
+            Rule::ignore => Ok(ConflictResolution::Ignore),
+            Rule::replace => Ok(ConflictResolution::Replace),
+            Rule::rollback_kw => Ok(ConflictResolution::Rollback),
+            _ => Err(ParseError::unexpected(
+                &pair,
+                &[
+                    Rule::abort,
+                    Rule::fail,
+                    Rule::ignore,
+                    Rule::replace,
+                    Rule::rollback_kw,
+                ],
+            )),
         }
     }
 }
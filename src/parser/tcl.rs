@@ -1,49 +1,58 @@
-use crate::{Rule, ast::*, parser::Parser};
+use crate::{
+    ParseError, Rule,
+    ast::*,
+    parser::{Children, Parser},
+};
 use pest::iterators::Pair;
 
 impl Parser for Begin {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let mode = inner
-            .next()
-            .map(|mode_pair| match mode_pair.as_rule() {
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let mode = match inner.next() {
+            Some(mode_pair) => match mode_pair.as_rule() {
                 Rule::deferred => TransactionMode::Deferred,
                 Rule::immediate => TransactionMode::Immediate,
                 Rule::exclusive => TransactionMode::Exclusive,
-                _ => unreachable!("Unexpected transaction mode: {:?}", mode_pair),
-            })
-            .unwrap_or(TransactionMode::Deferred); // 默认模式
+                _ => {
+                    return Err(ParseError::unexpected(
+                        &mode_pair,
+                        &[Rule::deferred, Rule::immediate, Rule::exclusive],
+                    ));
+                }
+            },
+            None => TransactionMode::Deferred, // 默认模式
+        };
 
-        Self(mode)
+        Ok(Self(mode))
     }
 }
 
 impl Parser for Commit {
-    fn parse(_: Pair<Rule>) -> Self {
-        Self
+    fn parse(_: Pair<Rule>) -> Result<Self, ParseError> {
+        Ok(Self)
     }
 }
 
 impl Parser for Rollback {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let savepoint_name = inner.next().map(|p| String::parse(p));
-        Self(savepoint_name)
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let savepoint_name = inner.next().map(String::parse).transpose()?;
+        Ok(Self(savepoint_name))
     }
 }
 
 impl Parser for Savepoint {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let name = String::parse(inner.next().unwrap());
-        Self(name)
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let name = String::parse(inner.expect()?)?;
+        Ok(Self(name))
     }
 }
 
 impl Parser for Release {
-    fn parse(pair: Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        let name = String::parse(inner.next().unwrap());
-        Self(name)
+    fn parse(pair: Pair<Rule>) -> Result<Self, ParseError> {
+        let mut inner = Children::new(pair);
+        let name = String::parse(inner.expect()?)?;
+        Ok(Self(name))
     }
 }
@@ -159,6 +159,8 @@ fn test_expr_samples() {
         "a + b * c / d - e % f",
         "a IS TRUE",
         "a IS NOT FALSE",
+        "nullif(a, b)",
+        "trueish OR falsey OR nullable",
     ];
 
     for sample in samples {
@@ -173,3 +175,769 @@ fn test_expr_samples() {
         );
     }
 }
+
+test_parse!(
+    test_expr_function,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "count(*)",
+            Expr::Function {
+                name: "count".to_owned(),
+                distinct: false,
+                args: FunctionArgs::Star,
+                filter: None,
+                order_by: vec![],
+                over: None
+            }
+        ),
+        (
+            "random()",
+            Expr::Function {
+                name: "random".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![]),
+                filter: None,
+                order_by: vec![],
+                over: None
+            }
+        ),
+        (
+            "lower(name) || 'x'",
+            Expr::Binary(
+                Box::new(Expr::Function {
+                    name: "lower".to_owned(),
+                    distinct: false,
+                    args: FunctionArgs::List(vec![Expr::QualifiedColumn(
+                        None,
+                        None,
+                        "name".to_owned()
+                    )]),
+                    filter: None,
+                    order_by: vec![],
+                    over: None
+                }),
+                BinaryOp::Concat,
+                Box::new(Expr::Literal(Literal::String("x".to_owned()))),
+            )
+        ),
+        (
+            "count(DISTINCT a) FILTER (WHERE a > 0)",
+            Expr::Function {
+                name: "count".to_owned(),
+                distinct: true,
+                args: FunctionArgs::List(vec![Expr::QualifiedColumn(None, None, "a".to_owned())]),
+                filter: Some(Box::new(Expr::Binary(
+                    Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                    BinaryOp::Gt,
+                    Box::new(Expr::Literal(Literal::Integer("0".to_owned()))),
+                ))),
+                order_by: vec![],
+                over: None
+            }
+        ),
+        (
+            "group_concat(name, ',' ORDER BY name DESC)",
+            Expr::Function {
+                name: "group_concat".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![
+                    Expr::QualifiedColumn(None, None, "name".to_owned()),
+                    Expr::Literal(Literal::String(",".to_owned())),
+                ]),
+                filter: None,
+                order_by: vec![OrderingTerm {
+                    expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                    asc: false,
+                    nulls_first: true,
+                }],
+                over: None
+            }
+        ),
+        (
+            "count(distinct_id)",
+            Expr::Function {
+                name: "count".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![Expr::QualifiedColumn(
+                    None,
+                    None,
+                    "distinct_id".to_owned()
+                )]),
+                filter: None,
+                order_by: vec![],
+                over: None
+            }
+        ),
+        (
+            "coalesce(nullif(a, ''), 'x')",
+            Expr::Function {
+                name: "coalesce".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![
+                    Expr::Function {
+                        name: "nullif".to_owned(),
+                        distinct: false,
+                        args: FunctionArgs::List(vec![
+                            Expr::QualifiedColumn(None, None, "a".to_owned()),
+                            Expr::Literal(Literal::String("".to_owned())),
+                        ]),
+                        filter: None,
+                        order_by: vec![],
+                        over: None
+                    },
+                    Expr::Literal(Literal::String("x".to_owned())),
+                ]),
+                filter: None,
+                order_by: vec![],
+                over: None
+            }
+        ),
+        (
+            "a = nullif(b, 0)",
+            Expr::Binary(
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                BinaryOp::Eq,
+                Box::new(Expr::Function {
+                    name: "nullif".to_owned(),
+                    distinct: false,
+                    args: FunctionArgs::List(vec![
+                        Expr::QualifiedColumn(None, None, "b".to_owned()),
+                        Expr::Literal(Literal::Integer("0".to_owned())),
+                    ]),
+                    filter: None,
+                    order_by: vec![],
+                    over: None
+                }),
+            )
+        ),
+        (
+            "REPLACE(a, 'x', 'y')",
+            Expr::Function {
+                name: "replace".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![
+                    Expr::QualifiedColumn(None, None, "a".to_owned()),
+                    Expr::Literal(Literal::String("x".to_owned())),
+                    Expr::Literal(Literal::String("y".to_owned())),
+                ]),
+                filter: None,
+                order_by: vec![],
+                over: None
+            }
+        ),
+        (
+            "like(a, b)",
+            Expr::Function {
+                name: "like".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![
+                    Expr::QualifiedColumn(None, None, "a".to_owned()),
+                    Expr::QualifiedColumn(None, None, "b".to_owned()),
+                ]),
+                filter: None,
+                order_by: vec![],
+                over: None
+            }
+        ),
+        (
+            "glob(a, b)",
+            Expr::Function {
+                name: "glob".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![
+                    Expr::QualifiedColumn(None, None, "a".to_owned()),
+                    Expr::QualifiedColumn(None, None, "b".to_owned()),
+                ]),
+                filter: None,
+                order_by: vec![],
+                over: None
+            }
+        ),
+    ]
+);
+
+test_parse!(
+    test_expr_window,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "count(*) overdue",
+            Expr::Function {
+                name: "count".to_owned(),
+                distinct: false,
+                args: FunctionArgs::Star,
+                filter: None,
+                order_by: vec![],
+                over: None
+            }
+        ),
+        (
+            "sum(x) OVER w",
+            Expr::Function {
+                name: "sum".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![Expr::QualifiedColumn(None, None, "x".to_owned())]),
+                filter: None,
+                order_by: vec![],
+                over: Some(Over::Name("w".to_owned()))
+            }
+        ),
+        (
+            "row_number() OVER (PARTITION BY dept ORDER BY salary DESC)",
+            Expr::Function {
+                name: "row_number".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![]),
+                filter: None,
+                order_by: vec![],
+                over: Some(Over::Window(WindowDefn {
+                    base: None,
+                    partition_by: vec![Expr::QualifiedColumn(None, None, "dept".to_owned())],
+                    order_by: vec![OrderingTerm {
+                        expr: Expr::QualifiedColumn(None, None, "salary".to_owned()),
+                        asc: false,
+                        nulls_first: true
+                    }],
+                    frame: None
+                }))
+            }
+        ),
+        (
+            "count(*) FILTER (WHERE x > 0) OVER (w ROWS BETWEEN UNBOUNDED PRECEDING AND 1 FOLLOWING EXCLUDE TIES)",
+            Expr::Function {
+                name: "count".to_owned(),
+                distinct: false,
+                args: FunctionArgs::Star,
+                filter: Some(Box::new(Expr::Binary(
+                    Box::new(Expr::QualifiedColumn(None, None, "x".to_owned())),
+                    BinaryOp::Gt,
+                    Box::new(Expr::Literal(Literal::Integer("0".to_owned())))
+                ))),
+                order_by: vec![],
+                over: Some(Over::Window(WindowDefn {
+                    base: Some("w".to_owned()),
+                    partition_by: vec![],
+                    order_by: vec![],
+                    frame: Some(FrameSpec {
+                        unit: FrameUnit::Rows,
+                        start: FrameBound::UnboundedPreceding,
+                        end: FrameBound::Following(Box::new(Expr::Literal(Literal::Integer(
+                            "1".to_owned()
+                        )))),
+                        exclude: FrameExclude::Ties
+                    })
+                }))
+            }
+        ),
+        (
+            "avg(x) OVER (RANGE 2 PRECEDING)",
+            Expr::Function {
+                name: "avg".to_owned(),
+                distinct: false,
+                args: FunctionArgs::List(vec![Expr::QualifiedColumn(None, None, "x".to_owned())]),
+                filter: None,
+                order_by: vec![],
+                over: Some(Over::Window(WindowDefn {
+                    base: None,
+                    partition_by: vec![],
+                    order_by: vec![],
+                    frame: Some(FrameSpec {
+                        unit: FrameUnit::Range,
+                        start: FrameBound::Preceding(Box::new(Expr::Literal(Literal::Integer(
+                            "2".to_owned()
+                        )))),
+                        end: FrameBound::CurrentRow,
+                        exclude: FrameExclude::NoOthers
+                    })
+                }))
+            }
+        ),
+    ]
+);
+
+#[test]
+fn test_expr_invalid_frame() {
+    use pest::Parser;
+
+    // UNBOUNDED FOLLOWING 不能作为起始边界，UNBOUNDED PRECEDING 不能作为结束边界
+    let inputs = [
+        "ROWS UNBOUNDED FOLLOWING",
+        "ROWS 1 FOLLOWING",
+        "ROWS BETWEEN UNBOUNDED FOLLOWING AND CURRENT ROW",
+        "ROWS BETWEEN CURRENT ROW AND UNBOUNDED PRECEDING",
+    ];
+    for input in inputs {
+        assert!(
+            SqlParser::parse(Rule::frame_spec, input).is_err(),
+            "'{}' should be rejected",
+            input
+        );
+    }
+}
+
+test_parse!(
+    test_expr_param,
+    Rule::expr,
+    Expr::parse,
+    [
+        ("?", Expr::Param(Param::Anonymous)),
+        ("?12", Expr::Param(Param::Numbered(12))),
+        (":name", Expr::Param(Param::Named(":name".to_owned()))),
+        ("@name", Expr::Param(Param::Named("@name".to_owned()))),
+        (
+            "id = $id",
+            Expr::Binary(
+                Box::new(Expr::QualifiedColumn(None, None, "id".to_owned())),
+                BinaryOp::Eq,
+                Box::new(Expr::Param(Param::Named("$id".to_owned())))
+            )
+        ),
+    ]
+);
+
+test_parse!(
+    test_expr_collate,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "created collated_at",
+            Expr::QualifiedColumn(None, None, "created".to_owned())
+        ),
+        (
+            "name COLLATE NOCASE",
+            Expr::Collate(
+                Box::new(Expr::QualifiedColumn(None, None, "name".to_owned())),
+                "nocase".to_owned()
+            )
+        ),
+        (
+            "a = b COLLATE rtrim",
+            Expr::Binary(
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                BinaryOp::Eq,
+                Box::new(Expr::Collate(
+                    Box::new(Expr::QualifiedColumn(None, None, "b".to_owned())),
+                    "rtrim".to_owned()
+                ))
+            )
+        ),
+        (
+            "a || b COLLATE binary",
+            Expr::Binary(
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                BinaryOp::Concat,
+                Box::new(Expr::Collate(
+                    Box::new(Expr::QualifiedColumn(None, None, "b".to_owned())),
+                    "binary".to_owned()
+                ))
+            )
+        ),
+    ]
+);
+
+test_parse!(
+    test_expr_null_test,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "a ISNULL",
+            Expr::Unary(
+                UnaryOp::IsNull,
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned()))
+            )
+        ),
+        (
+            "a NOTNULL",
+            Expr::Unary(
+                UnaryOp::NotNull,
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned()))
+            )
+        ),
+        (
+            "a + 1 NOT NULL AND b",
+            Expr::Binary(
+                Box::new(Expr::Unary(
+                    UnaryOp::NotNull,
+                    Box::new(Expr::Binary(
+                        Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                        BinaryOp::Plus,
+                        Box::new(Expr::Literal(Literal::Integer("1".to_owned())))
+                    ))
+                )),
+                BinaryOp::LogicalAnd,
+                Box::new(Expr::QualifiedColumn(None, None, "b".to_owned()))
+            )
+        ),
+        (
+            "a IS NOT NULL",
+            Expr::Binary(
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                BinaryOp::IsNot,
+                Box::new(Expr::Literal(Literal::Null))
+            )
+        ),
+        (
+            "a IS DISTINCT FROM b",
+            Expr::Binary(
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                BinaryOp::IsDistinctFrom,
+                Box::new(Expr::QualifiedColumn(None, None, "b".to_owned()))
+            )
+        ),
+        (
+            "a IS NOT DISTINCT FROM b",
+            Expr::Binary(
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                BinaryOp::IsNotDistinctFrom,
+                Box::new(Expr::QualifiedColumn(None, None, "b".to_owned()))
+            )
+        ),
+        (
+            "a IS notable",
+            Expr::Binary(
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                BinaryOp::Is,
+                Box::new(Expr::QualifiedColumn(None, None, "notable".to_owned()))
+            )
+        ),
+    ]
+);
+
+test_parse!(
+    test_expr_case,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "CASE x WHEN 1 THEN 'one' WHEN 2 THEN 'two' END",
+            Expr::Case {
+                operand: Some(Box::new(Expr::QualifiedColumn(None, None, "x".to_owned()))),
+                when_then: vec![
+                    (
+                        Expr::Literal(Literal::Integer("1".to_owned())),
+                        Expr::Literal(Literal::String("one".to_owned())),
+                    ),
+                    (
+                        Expr::Literal(Literal::Integer("2".to_owned())),
+                        Expr::Literal(Literal::String("two".to_owned())),
+                    ),
+                ],
+                else_expr: None,
+            }
+        ),
+        (
+            "CASE WHEN a > 0 THEN a ELSE -a END + 1",
+            Expr::Binary(
+                Box::new(Expr::Case {
+                    operand: None,
+                    when_then: vec![(
+                        Expr::Binary(
+                            Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                            BinaryOp::Gt,
+                            Box::new(Expr::Literal(Literal::Integer("0".to_owned()))),
+                        ),
+                        Expr::QualifiedColumn(None, None, "a".to_owned()),
+                    )],
+                    else_expr: Some(Box::new(Expr::Unary(
+                        UnaryOp::Negative,
+                        Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                    ))),
+                }),
+                BinaryOp::Plus,
+                Box::new(Expr::Literal(Literal::Integer("1".to_owned()))),
+            )
+        ),
+    ]
+);
+
+test_parse!(
+    test_expr_cast,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "CAST(a AS INTEGER)",
+            Expr::Cast(
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                TypeName {
+                    name: "integer".to_owned(),
+                    size: None,
+                }
+            )
+        ),
+        (
+            "cast(price * 100 as decimal(10, 2))",
+            Expr::Cast(
+                Box::new(Expr::Binary(
+                    Box::new(Expr::QualifiedColumn(None, None, "price".to_owned())),
+                    BinaryOp::Mul,
+                    Box::new(Expr::Literal(Literal::Integer("100".to_owned()))),
+                )),
+                TypeName {
+                    name: "decimal".to_owned(),
+                    size: Some(TypeSize::TypeSize("10".to_owned(), "2".to_owned())),
+                }
+            )
+        ),
+    ]
+);
+
+test_parse!(
+    test_expr_in,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "a IN (1, 2)",
+            Expr::In {
+                expr: Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                not: false,
+                rhs: InValues::List(vec![
+                    Expr::Literal(Literal::Integer("1".to_owned())),
+                    Expr::Literal(Literal::Integer("2".to_owned())),
+                ]),
+            }
+        ),
+        (
+            "a NOT IN ()",
+            Expr::In {
+                expr: Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                not: true,
+                rhs: InValues::List(vec![]),
+            }
+        ),
+        (
+            "a in main.ids",
+            Expr::In {
+                expr: Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                not: false,
+                rhs: InValues::Table(SchemaObject {
+                    schema_name: Some("main".to_owned()),
+                    name: "ids".to_owned(),
+                }),
+            }
+        ),
+        (
+            "a + 1 IN (b) AND c",
+            Expr::Binary(
+                Box::new(Expr::In {
+                    expr: Box::new(Expr::Binary(
+                        Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                        BinaryOp::Plus,
+                        Box::new(Expr::Literal(Literal::Integer("1".to_owned()))),
+                    )),
+                    not: false,
+                    rhs: InValues::List(vec![Expr::QualifiedColumn(None, None, "b".to_owned())]),
+                }),
+                BinaryOp::LogicalAnd,
+                Box::new(Expr::QualifiedColumn(None, None, "c".to_owned())),
+            )
+        ),
+        (
+            "NOT a IN (b)",
+            Expr::Unary(
+                UnaryOp::LogicalNot,
+                Box::new(Expr::In {
+                    expr: Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                    not: false,
+                    rhs: InValues::List(vec![Expr::QualifiedColumn(None, None, "b".to_owned())]),
+                }),
+            )
+        ),
+    ]
+);
+
+test_parse!(
+    test_expr_pattern_match,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "name LIKE 'a%'",
+            Expr::Like {
+                expr: Box::new(Expr::QualifiedColumn(None, None, "name".to_owned())),
+                op: LikeOp::Like,
+                not: false,
+                pattern: Box::new(Expr::Literal(Literal::String("a%".to_owned()))),
+                escape: None,
+            }
+        ),
+        (
+            "name NOT GLOB '*.txt' OR name regexp '^x'",
+            Expr::Binary(
+                Box::new(Expr::Like {
+                    expr: Box::new(Expr::QualifiedColumn(None, None, "name".to_owned())),
+                    op: LikeOp::Glob,
+                    not: true,
+                    pattern: Box::new(Expr::Literal(Literal::String("*.txt".to_owned()))),
+                    escape: None,
+                }),
+                BinaryOp::LogicalOr,
+                Box::new(Expr::Like {
+                    expr: Box::new(Expr::QualifiedColumn(None, None, "name".to_owned())),
+                    op: LikeOp::Regexp,
+                    not: false,
+                    pattern: Box::new(Expr::Literal(Literal::String("^x".to_owned()))),
+                    escape: None,
+                }),
+            )
+        ),
+        (
+            "name NOT LIKE prefix || '!%' ESCAPE '!' AND a",
+            Expr::Binary(
+                Box::new(Expr::Like {
+                    expr: Box::new(Expr::QualifiedColumn(None, None, "name".to_owned())),
+                    op: LikeOp::Like,
+                    not: true,
+                    pattern: Box::new(Expr::Binary(
+                        Box::new(Expr::QualifiedColumn(None, None, "prefix".to_owned())),
+                        BinaryOp::Concat,
+                        Box::new(Expr::Literal(Literal::String("!%".to_owned()))),
+                    )),
+                    escape: Some(Box::new(Expr::Literal(Literal::String("!".to_owned())))),
+                }),
+                BinaryOp::LogicalAnd,
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+            )
+        ),
+        (
+            "docs MATCH 'sqlite' = 1",
+            Expr::Binary(
+                Box::new(Expr::Like {
+                    expr: Box::new(Expr::QualifiedColumn(None, None, "docs".to_owned())),
+                    op: LikeOp::Match,
+                    not: false,
+                    pattern: Box::new(Expr::Literal(Literal::String("sqlite".to_owned()))),
+                    escape: None,
+                }),
+                BinaryOp::Eq,
+                Box::new(Expr::Literal(Literal::Integer("1".to_owned()))),
+            )
+        ),
+        (
+            "a + 1 LIKE b COLLATE nocase",
+            Expr::Like {
+                expr: Box::new(Expr::Binary(
+                    Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                    BinaryOp::Plus,
+                    Box::new(Expr::Literal(Literal::Integer("1".to_owned()))),
+                )),
+                op: LikeOp::Like,
+                not: false,
+                pattern: Box::new(Expr::Collate(
+                    Box::new(Expr::QualifiedColumn(None, None, "b".to_owned())),
+                    "nocase".to_owned(),
+                )),
+                escape: None,
+            }
+        ),
+        (
+            "a IS b = c",
+            Expr::Binary(
+                Box::new(Expr::Binary(
+                    Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                    BinaryOp::Is,
+                    Box::new(Expr::QualifiedColumn(None, None, "b".to_owned())),
+                )),
+                BinaryOp::Eq,
+                Box::new(Expr::QualifiedColumn(None, None, "c".to_owned())),
+            )
+        ),
+    ]
+);
+
+#[test]
+fn test_expr_keyword_boundary() {
+    use pest::Parser;
+
+    // 关键字后紧跟标识符字符时不应被识别为运算符
+    for input in ["a NOTIN (1)", "a notin b", "a NOTLIKE b", "a likely b"] {
+        let pairs = SqlParser::parse(Rule::expr, input).unwrap();
+        assert_eq!(
+            pairs.as_str().trim_end(),
+            "a",
+            "'{}' should stop before the operator",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_expr_escape_without_like() {
+    use pest::Parser;
+
+    // ESCAPE 只能跟在模式匹配之后
+    let pairs = SqlParser::parse(Rule::expr, "a = b ESCAPE '!'").unwrap();
+    assert_eq!(pairs.as_str().trim_end(), "a = b");
+}
+
+test_parse!(
+    test_expr_subquery,
+    Rule::expr,
+    Expr::parse,
+    [
+        (
+            "NOT EXISTS (SELECT 1)",
+            Expr::Exists {
+                not: true,
+                select: Box::new(Select {
+                    with_clause: None,
+                    core: SelectCore::Query {
+                        is_distinct: false,
+                        columns: vec![ResultColumn::Expr(
+                            Expr::Literal(Literal::Integer("1".to_owned())),
+                            None
+                        )],
+                        from_clause: None,
+                        where_clause: None,
+                        group_by: vec![],
+                        having: None,
+                        window: vec![]
+                    },
+                    compound: vec![],
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                }),
+            }
+        ),
+        (
+            "(SELECT 1) + 1",
+            Expr::Binary(
+                Box::new(Expr::Subquery(Box::new(Select {
+                    with_clause: None,
+                    core: SelectCore::Query {
+                        is_distinct: false,
+                        columns: vec![ResultColumn::Expr(
+                            Expr::Literal(Literal::Integer("1".to_owned())),
+                            None
+                        )],
+                        from_clause: None,
+                        where_clause: None,
+                        group_by: vec![],
+                        having: None,
+                        window: vec![]
+                    },
+                    compound: vec![],
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                }))),
+                BinaryOp::Plus,
+                Box::new(Expr::Literal(Literal::Integer("1".to_owned()))),
+            )
+        ),
+        (
+            "NOT a",
+            Expr::Unary(
+                UnaryOp::LogicalNot,
+                Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+            )
+        ),
+    ]
+);
@@ -9,7 +9,10 @@ macro_rules! test_parse {
                     Ok(mut pairs) => pairs.next().unwrap(),
                     Err(e) => panic!("Failed to parse input '{}': {}", input, e),
                 };
-                let ast = $parser(pair);
+                let ast = match $parser(pair) {
+                    Ok(ast) => ast,
+                    Err(e) => panic!("Failed to build AST for '{}': {}", input, e),
+                };
                 assert_eq!(ast, expected);
             }
         }
@@ -41,11 +41,11 @@ test_parse!(
                             constraints: vec![
                                 ColumnConstraint {
                                     name: None,
-                                    ty: ColumnConstraintType::Unique
+                                    ty: ColumnConstraintType::Unique { conflict: None }
                                 },
                                 ColumnConstraint {
                                     name: None,
-                                    ty: ColumnConstraintType::NotNull
+                                    ty: ColumnConstraintType::NotNull { conflict: None }
                                 },
                             ],
                         },
@@ -58,22 +58,25 @@ test_parse!(
                             constraints: vec![
                                 ColumnConstraint {
                                     name: None,
-                                    ty: ColumnConstraintType::Unique
+                                    ty: ColumnConstraintType::Unique { conflict: None }
                                 },
                                 ColumnConstraint {
                                     name: None,
-                                    ty: ColumnConstraintType::NotNull
+                                    ty: ColumnConstraintType::NotNull { conflict: None }
                                 },
                             ],
                         },
                     ],
                     table_constraints: vec![TableConstraint {
                         name: Some("pk".to_owned()),
-                        cols: vec![IndexedColumn {
-                            name: "id".to_owned(),
-                            asc: true,
-                        }],
-                        ty: TableConstraintType::PrimaryKey,
+                        ty: TableConstraintType::PrimaryKey {
+                            cols: vec![IndexedColumn {
+                                expr: Expr::QualifiedColumn(None, None, "id".to_owned()),
+                                collation: None,
+                                asc: true,
+                            }],
+                            conflict: None
+                        },
                     },],
                     table_options: vec![],
                 }
@@ -102,6 +105,58 @@ test_parse!(
     ]
 );
 
+test_parse!(
+    test_create_virtual_table,
+    Rule::create_virtual_table,
+    CreateVirtualTable::parse,
+    [
+        (
+            "CREATE VIRTUAL TABLE docs USING fts5(title, body, tokenize='porter')",
+            CreateVirtualTable {
+                if_not_exists: false,
+                schema_table: SchemaObject {
+                    schema_name: None,
+                    name: "docs".to_owned(),
+                },
+                module: "fts5".to_owned(),
+                args: vec![
+                    "title".to_owned(),
+                    "body".to_owned(),
+                    "tokenize='porter'".to_owned(),
+                ],
+            }
+        ),
+        (
+            "create virtual table if not exists main.t using m( a (1, 2) ,'x, y', f(g(h)) )",
+            CreateVirtualTable {
+                if_not_exists: true,
+                schema_table: SchemaObject {
+                    schema_name: Some("main".to_owned()),
+                    name: "t".to_owned(),
+                },
+                module: "m".to_owned(),
+                args: vec![
+                    "a (1, 2)".to_owned(),
+                    "'x, y'".to_owned(),
+                    "f(g(h))".to_owned(),
+                ],
+            }
+        ),
+        (
+            "CREATE VIRTUAL TABLE dbstat_view USING dbstat",
+            CreateVirtualTable {
+                if_not_exists: false,
+                schema_table: SchemaObject {
+                    schema_name: None,
+                    name: "dbstat_view".to_owned(),
+                },
+                module: "dbstat".to_owned(),
+                args: vec![],
+            }
+        ),
+    ]
+);
+
 test_parse!(
     test_drop_table,
     Rule::drop_table,
@@ -168,7 +223,8 @@ test_parse!(
             },
             table_name: "employee".to_owned(),
             indexed_cols: vec![IndexedColumn {
-                name: "eage".to_owned(),
+                expr: Expr::QualifiedColumn(None, None, "eage".to_owned()),
+                collation: None,
                 asc: false,
             },],
             where_cond: None,
@@ -207,6 +263,7 @@ test_parse!(
             },
             columns: vec!["ename".to_owned(), "eage".to_owned()],
             select: Select {
+                with_clause: None,
                 core: SelectCore::Query {
                     is_distinct: false,
                     columns: vec![
@@ -219,21 +276,24 @@ test_parse!(
                             None
                         ),
                     ],
-                    from_clause: Some(FromClause::TableOrQuerys(vec![QualifiedTable {
-                        schema_table: SchemaObject {
-                            schema_name: None,
-                            name: "employee".to_owned(),
-                        },
-                        alias: None,
-                        indexed: None,
-                    }])),
+                    from_clause: Some(FromClause::TableOrQuerys(vec![TableOrSubquery::Table(
+                        QualifiedTable {
+                            schema_table: SchemaObject {
+                                schema_name: None,
+                                name: "employee".to_owned(),
+                            },
+                            alias: None,
+                            indexed: None,
+                        }
+                    )])),
                     where_clause: Some(Expr::Binary(
                         Box::new(Expr::QualifiedColumn(None, None, "eage".to_owned())),
                         BinaryOp::Gt,
                         Box::new(Expr::Literal(Literal::Integer("30".to_owned()))),
                     )),
                     group_by: vec![],
-                    having: None
+                    having: None,
+                    window: vec![]
                 },
                 compound: vec![],
                 order_by: vec![],
@@ -271,6 +331,7 @@ test_parse!(
                 name: None,
                 ty: ColumnConstraintType::PrimaryKey {
                     asc: true,
+                    conflict: None,
                     auto_inc: false
                 }
             }
@@ -281,6 +342,7 @@ test_parse!(
                 name: Some("pk".to_owned()),
                 ty: ColumnConstraintType::PrimaryKey {
                     asc: true,
+                    conflict: None,
                     auto_inc: true
                 }
             }
@@ -289,7 +351,165 @@ test_parse!(
             "default 0.",
             ColumnConstraint {
                 name: None,
-                ty: ColumnConstraintType::Default(Literal::Decimal("0.".to_owned()))
+                ty: ColumnConstraintType::Default(DefaultValue::Literal(Literal::Decimal(
+                    "0.".to_owned()
+                )))
+            }
+        ),
+        (
+            "default - 1.5",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Default(DefaultValue::SignedNumber(Literal::Decimal(
+                    "-1.5".to_owned()
+                )))
+            }
+        ),
+        (
+            "default +3",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Default(DefaultValue::SignedNumber(Literal::Integer(
+                    "+3".to_owned()
+                )))
+            }
+        ),
+        (
+            "default (1 + 2)",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Default(DefaultValue::Expr(Expr::Binary(
+                    Box::new(Expr::Literal(Literal::Integer("1".to_owned()))),
+                    BinaryOp::Plus,
+                    Box::new(Expr::Literal(Literal::Integer("2".to_owned())))
+                )))
+            }
+        ),
+        (
+            "default current_timestamp",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Default(DefaultValue::CurrentTimestamp)
+            }
+        ),
+        (
+            "default CURRENT_TIME",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Default(DefaultValue::CurrentTime)
+            }
+        ),
+        (
+            "default current_date",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Default(DefaultValue::CurrentDate)
+            }
+        ),
+        (
+            "default pending",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Default(DefaultValue::Ident("pending".to_owned()))
+            }
+        ),
+        (
+            "REFERENCES users(id) ON DELETE CASCADE ON UPDATE NO ACTION DEFERRABLE INITIALLY DEFERRED",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::ForeignKey(ForeignKeyClause {
+                    foreign_table: "users".to_owned(),
+                    columns: vec!["id".to_owned()],
+                    on_delete: FkAction::Cascade,
+                    on_update: FkAction::NoAction,
+                    match_name: None,
+                    deferrable: Some(Deferrable {
+                        not: false,
+                        initially: Some(Initially::Deferred)
+                    })
+                })
+            }
+        ),
+        (
+            "GENERATED ALWAYS AS (price * qty) STORED",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Generated {
+                    expr: Expr::Binary(
+                        Box::new(Expr::QualifiedColumn(None, None, "price".to_owned())),
+                        BinaryOp::Mul,
+                        Box::new(Expr::QualifiedColumn(None, None, "qty".to_owned()))
+                    ),
+                    stored: true
+                }
+            }
+        ),
+        (
+            "AS (lower(name))",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Generated {
+                    expr: Expr::Function {
+                        name: "lower".to_owned(),
+                        distinct: false,
+                        args: FunctionArgs::List(vec![Expr::QualifiedColumn(
+                            None,
+                            None,
+                            "name".to_owned()
+                        )]),
+                        filter: None,
+                        order_by: vec![],
+                        over: None
+                    },
+                    stored: false
+                }
+            }
+        ),
+        (
+            "PRIMARY KEY DESC ON CONFLICT IGNORE AUTOINCREMENT",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::PrimaryKey {
+                    asc: false,
+                    conflict: Some(ConflictResolution::Ignore),
+                    auto_inc: true
+                }
+            }
+        ),
+        (
+            "PRIMARY KEY ON CONFLICT FAIL",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::PrimaryKey {
+                    asc: true,
+                    conflict: Some(ConflictResolution::Fail),
+                    auto_inc: false
+                }
+            }
+        ),
+        (
+            "UNIQUE ON CONFLICT REPLACE",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Unique {
+                    conflict: Some(ConflictResolution::Replace)
+                }
+            }
+        ),
+        (
+            "NOT NULL ON CONFLICT ABORT",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::NotNull {
+                    conflict: Some(ConflictResolution::Abort)
+                }
+            }
+        ),
+        (
+            "COLLATE NOCASE",
+            ColumnConstraint {
+                name: None,
+                ty: ColumnConstraintType::Collate("nocase".to_owned())
             }
         ),
     ]
@@ -325,17 +545,46 @@ test_parse!(
         (
             "name desc",
             IndexedColumn {
-                name: "name".to_owned(),
+                expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                collation: None,
                 asc: false
             }
         ),
         (
             "name asc",
             IndexedColumn {
-                name: "name".to_owned(),
+                expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                collation: None,
                 asc: true
             }
         ),
+        (
+            "lower(name) DESC",
+            IndexedColumn {
+                expr: Expr::Function {
+                    name: "lower".to_owned(),
+                    distinct: false,
+                    args: FunctionArgs::List(vec![Expr::QualifiedColumn(
+                        None,
+                        None,
+                        "name".to_owned()
+                    )]),
+                    filter: None,
+                    order_by: vec![],
+                    over: None
+                },
+                collation: None,
+                asc: false
+            }
+        ),
+        (
+            "name COLLATE nocase DESC",
+            IndexedColumn {
+                expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                collation: Some("nocase".to_owned()),
+                asc: false
+            }
+        ),
     ]
 );
 
@@ -348,28 +597,87 @@ test_parse!(
             "constraint prime primary key (name desc)",
             TableConstraint {
                 name: Some("prime".to_owned()),
-                cols: vec![IndexedColumn {
-                    name: "name".to_owned(),
-                    asc: false,
-                }],
-                ty: TableConstraintType::PrimaryKey,
+                ty: TableConstraintType::PrimaryKey {
+                    cols: vec![IndexedColumn {
+                        expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                        collation: None,
+                        asc: false,
+                    }],
+                    conflict: None
+                },
             }
         ),
         (
             "constraint uni unique (name desc, age asc)",
             TableConstraint {
                 name: Some("uni".to_owned()),
-                cols: vec![
-                    IndexedColumn {
-                        name: "name".to_owned(),
-                        asc: false,
-                    },
-                    IndexedColumn {
-                        name: "age".to_owned(),
-                        asc: true,
+                ty: TableConstraintType::Unique {
+                    cols: vec![
+                        IndexedColumn {
+                            expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                            collation: None,
+                            asc: false,
+                        },
+                        IndexedColumn {
+                            expr: Expr::QualifiedColumn(None, None, "age".to_owned()),
+                            collation: None,
+                            asc: true,
+                        }
+                    ],
+                    conflict: None
+                },
+            }
+        ),
+        (
+            "CONSTRAINT c CHECK (a < b)",
+            TableConstraint {
+                name: Some("c".to_owned()),
+                ty: TableConstraintType::Check(Expr::Binary(
+                    Box::new(Expr::QualifiedColumn(None, None, "a".to_owned())),
+                    BinaryOp::Lt,
+                    Box::new(Expr::QualifiedColumn(None, None, "b".to_owned()))
+                )),
+            }
+        ),
+        (
+            "PRIMARY KEY (id, name COLLATE nocase) ON CONFLICT REPLACE",
+            TableConstraint {
+                name: None,
+                ty: TableConstraintType::PrimaryKey {
+                    cols: vec![
+                        IndexedColumn {
+                            expr: Expr::QualifiedColumn(None, None, "id".to_owned()),
+                            collation: None,
+                            asc: true
+                        },
+                        IndexedColumn {
+                            expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                            collation: Some("nocase".to_owned()),
+                            asc: true
+                        }
+                    ],
+                    conflict: Some(ConflictResolution::Replace)
+                },
+            }
+        ),
+        (
+            "foreign key (a, b) references parent (x, y) on update set null match simple not deferrable",
+            TableConstraint {
+                name: None,
+                ty: TableConstraintType::ForeignKey {
+                    columns: vec!["a".to_owned(), "b".to_owned()],
+                    clause: ForeignKeyClause {
+                        foreign_table: "parent".to_owned(),
+                        columns: vec!["x".to_owned(), "y".to_owned()],
+                        on_delete: FkAction::NoAction,
+                        on_update: FkAction::SetNull,
+                        match_name: Some("simple".to_owned()),
+                        deferrable: Some(Deferrable {
+                            not: true,
+                            initially: None
+                        })
                     }
-                ],
-                ty: TableConstraintType::Unique,
+                },
             }
         ),
     ]
@@ -391,11 +699,11 @@ test_parse!(
                 constraints: vec![
                     ColumnConstraint {
                         name: None,
-                        ty: ColumnConstraintType::Unique
+                        ty: ColumnConstraintType::Unique { conflict: None }
                     },
                     ColumnConstraint {
                         name: None,
-                        ty: ColumnConstraintType::NotNull
+                        ty: ColumnConstraintType::NotNull { conflict: None }
                     },
                 ],
             }
@@ -418,7 +726,7 @@ test_parse!(
                 col_type: None,
                 constraints: vec![ColumnConstraint {
                     name: None,
-                    ty: ColumnConstraintType::NotNull
+                    ty: ColumnConstraintType::NotNull { conflict: None }
                 }],
             }
         ),
@@ -433,20 +741,24 @@ test_parse!(
         (
             "AS SELECT * FROM users",
             CreateTableBody::Select(Select {
+                with_clause: None,
                 core: SelectCore::Query {
                     is_distinct: false,
                     columns: vec![ResultColumn::Star],
-                    from_clause: Some(FromClause::TableOrQuerys(vec![QualifiedTable {
-                        schema_table: SchemaObject {
-                            schema_name: None,
-                            name: "users".to_owned(),
-                        },
-                        alias: None,
-                        indexed: None,
-                    }])),
+                    from_clause: Some(FromClause::TableOrQuerys(vec![TableOrSubquery::Table(
+                        QualifiedTable {
+                            schema_table: SchemaObject {
+                                schema_name: None,
+                                name: "users".to_owned(),
+                            },
+                            alias: None,
+                            indexed: None,
+                        }
+                    )])),
                     where_clause: None,
                     group_by: vec![],
                     having: None,
+                    window: vec![]
                 },
                 compound: vec![],
                 order_by: vec![],
@@ -467,11 +779,14 @@ test_parse!(
                 },],
                 table_constraints: vec![TableConstraint {
                     name: None,
-                    cols: vec![IndexedColumn {
-                        name: "name".to_owned(),
-                        asc: true,
-                    }],
-                    ty: TableConstraintType::Unique,
+                    ty: TableConstraintType::Unique {
+                        cols: vec![IndexedColumn {
+                            expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                            collation: None,
+                            asc: true,
+                        }],
+                        conflict: Some(ConflictResolution::Rollback)
+                    },
                 },],
                 table_options: vec![TableOption::Strict]
             }
@@ -501,6 +816,7 @@ test_parse!(
             table_name: "employee".to_owned(),
             when_cond: None,
             statements: vec![Dml::Update(Update {
+                with_clause: None,
                 conflict: ConflictResolution::Abort,
                 qualified_table: QualifiedTable {
                     schema_table: SchemaObject {
@@ -92,21 +92,24 @@ test_parse!(
                     ResultColumn::Expr(Expr::QualifiedColumn(None, None, "id".to_owned()), None),
                     ResultColumn::Expr(Expr::QualifiedColumn(None, None, "name".to_owned()), None),
                 ],
-                from_clause: Some(FromClause::TableOrQuerys(vec![QualifiedTable {
-                    schema_table: SchemaObject {
-                        schema_name: Some("sql".to_owned()),
-                        name: "users".to_owned(),
-                    },
-                    alias: None,
-                    indexed: None,
-                }])),
+                from_clause: Some(FromClause::TableOrQuerys(vec![TableOrSubquery::Table(
+                    QualifiedTable {
+                        schema_table: SchemaObject {
+                            schema_name: Some("sql".to_owned()),
+                            name: "users".to_owned(),
+                        },
+                        alias: None,
+                        indexed: None,
+                    }
+                )])),
                 where_clause: Some(Expr::Binary(
                     Box::new(Expr::QualifiedColumn(None, None, "age".to_owned())),
                     BinaryOp::Gt,
                     Box::new(Expr::Literal(Literal::Integer("30".to_owned()))),
                 )),
                 group_by: vec![],
-                having: None
+                having: None,
+                window: vec![]
             }
         )
     ]
@@ -125,6 +128,7 @@ test_parse!(
             order by name
             limit 10 offset 5",
             Select {
+                with_clause: None,
                 core: SelectCore::Query {
                     is_distinct: false,
                     columns: vec![
@@ -137,14 +141,16 @@ test_parse!(
                             None
                         ),
                     ],
-                    from_clause: Some(FromClause::TableOrQuerys(vec![QualifiedTable {
-                        schema_table: SchemaObject {
-                            schema_name: Some("sql".to_owned()),
-                            name: "users".to_owned(),
-                        },
-                        alias: Some("u".to_owned()),
-                        indexed: None,
-                    }])),
+                    from_clause: Some(FromClause::TableOrQuerys(vec![TableOrSubquery::Table(
+                        QualifiedTable {
+                            schema_table: SchemaObject {
+                                schema_name: Some("sql".to_owned()),
+                                name: "users".to_owned(),
+                            },
+                            alias: Some("u".to_owned()),
+                            indexed: None,
+                        }
+                    )])),
                     where_clause: Some(Expr::Binary(
                         Box::new(Expr::QualifiedColumn(None, None, "age".to_owned())),
                         BinaryOp::Gt,
@@ -156,6 +162,7 @@ test_parse!(
                         BinaryOp::Gt,
                         Box::new(Expr::Literal(Literal::Integer("20".to_owned()))),
                     )),
+                    window: vec![]
                 },
                 compound: vec![],
                 order_by: vec![OrderingTerm {
@@ -172,6 +179,7 @@ test_parse!(
             order by name
             limit 10 offset 5",
             Select {
+                with_clause: None,
                 core: SelectCore::Query {
                     is_distinct: false,
                     columns: vec![
@@ -184,17 +192,20 @@ test_parse!(
                             None
                         ),
                     ],
-                    from_clause: Some(FromClause::TableOrQuerys(vec![QualifiedTable {
-                        schema_table: SchemaObject {
-                            schema_name: Some("sql".to_owned()),
-                            name: "users".to_owned(),
-                        },
-                        alias: None,
-                        indexed: None,
-                    }])),
+                    from_clause: Some(FromClause::TableOrQuerys(vec![TableOrSubquery::Table(
+                        QualifiedTable {
+                            schema_table: SchemaObject {
+                                schema_name: Some("sql".to_owned()),
+                                name: "users".to_owned(),
+                            },
+                            alias: None,
+                            indexed: None,
+                        }
+                    )])),
                     where_clause: None,
                     group_by: vec![],
                     having: None,
+                    window: vec![]
                 },
                 compound: vec![(
                     CompoundOperator::Union(true),
@@ -204,17 +215,20 @@ test_parse!(
                             Expr::QualifiedColumn(None, None, "age".to_owned()),
                             None,
                         )],
-                        from_clause: Some(FromClause::TableOrQuerys(vec![QualifiedTable {
-                            schema_table: SchemaObject {
-                                schema_name: Some("sql".to_owned()),
-                                name: "users".to_owned(),
-                            },
-                            alias: None,
-                            indexed: None,
-                        }])),
+                        from_clause: Some(FromClause::TableOrQuerys(vec![TableOrSubquery::Table(
+                            QualifiedTable {
+                                schema_table: SchemaObject {
+                                    schema_name: Some("sql".to_owned()),
+                                    name: "users".to_owned(),
+                                },
+                                alias: None,
+                                indexed: None,
+                            }
+                        )])),
                         where_clause: None,
                         group_by: vec![],
                         having: None,
+                        window: vec![]
                     }
                 )],
                 order_by: vec![OrderingTerm {
@@ -237,6 +251,7 @@ test_parse!(
         (
             "Replace into sql.users (id, name) values (1, 'Alice'), (2, 'Bob')",
             Insert {
+                with_clause: None,
                 header: InsertHeader::Replace,
                 schema_table: SchemaObject {
                     schema_name: Some("sql".to_owned()),
@@ -263,6 +278,7 @@ test_parse!(
         (
             "Insert OR ROLLBACK into sql.users (id, name) values (1, 'Alice'), (2, 'Bob') returning id",
             Insert {
+                with_clause: None,
                 header: InsertHeader::Insert(ConflictResolution::Rollback),
                 schema_table: SchemaObject {
                     schema_name: Some("sql".to_owned()),
@@ -381,24 +397,24 @@ test_parse!(
     [(
         "user left join orders on user.id = orders.user_id",
         JoinClause {
-            table_or_subquery: QualifiedTable {
+            table_or_subquery: TableOrSubquery::Table(QualifiedTable {
                 schema_table: SchemaObject {
                     schema_name: None,
                     name: "user".to_owned()
                 },
                 alias: None,
                 indexed: None
-            },
+            }),
             joins: vec![JoinSubClause {
                 operator: JoinOperator::Outer(false, OuterJoinType::Left),
-                table_or_subquery: QualifiedTable {
+                table_or_subquery: TableOrSubquery::Table(QualifiedTable {
                     schema_table: SchemaObject {
                         schema_name: None,
                         name: "orders".to_owned()
                     },
                     alias: None,
                     indexed: None
-                },
+                }),
                 constraint: Some(JoinConstraint::Expr(Expr::Binary(
                     Box::new(Expr::QualifiedColumn(
                         None,
@@ -417,6 +433,269 @@ test_parse!(
     ),]
 );
 
+test_parse!(
+    test_table_or_subquery,
+    Rule::table_or_subquery,
+    TableOrSubquery::parse,
+    [
+        (
+            "json_each(data) AS j",
+            TableOrSubquery::TableFunction {
+                schema_function: SchemaObject {
+                    schema_name: None,
+                    name: "json_each".to_owned()
+                },
+                args: vec![Expr::QualifiedColumn(None, None, "data".to_owned())],
+                alias: Some("j".to_owned())
+            }
+        ),
+        (
+            "(VALUES (1)) v",
+            TableOrSubquery::Subquery {
+                select: Box::new(Select {
+                    with_clause: None,
+                    core: SelectCore::Values(vec![vec![Expr::Literal(Literal::Integer(
+                        "1".to_owned()
+                    ))]]),
+                    compound: vec![],
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                }),
+                alias: Some("v".to_owned())
+            }
+        ),
+        (
+            "(a CROSS JOIN b)",
+            TableOrSubquery::Join(Box::new(JoinClause {
+                table_or_subquery: TableOrSubquery::Table(QualifiedTable {
+                    schema_table: SchemaObject {
+                        schema_name: None,
+                        name: "a".to_owned()
+                    },
+                    alias: None,
+                    indexed: None
+                }),
+                joins: vec![JoinSubClause {
+                    operator: JoinOperator::Cross,
+                    table_or_subquery: TableOrSubquery::Table(QualifiedTable {
+                        schema_table: SchemaObject {
+                            schema_name: None,
+                            name: "b".to_owned()
+                        },
+                        alias: None,
+                        indexed: None
+                    }),
+                    constraint: None
+                }]
+            }))
+        ),
+        (
+            "json_each(x) ascending",
+            TableOrSubquery::TableFunction {
+                schema_function: SchemaObject {
+                    schema_name: None,
+                    name: "json_each".to_owned()
+                },
+                args: vec![Expr::QualifiedColumn(None, None, "x".to_owned())],
+                alias: Some("ascending".to_owned())
+            }
+        ),
+        (
+            "(SELECT 1) ascending",
+            TableOrSubquery::Subquery {
+                select: Box::new(Select {
+                    with_clause: None,
+                    core: SelectCore::Query {
+                        is_distinct: false,
+                        columns: vec![ResultColumn::Expr(
+                            Expr::Literal(Literal::Integer("1".to_owned())),
+                            None
+                        )],
+                        from_clause: None,
+                        where_clause: None,
+                        group_by: vec![],
+                        having: None,
+                        window: vec![]
+                    },
+                    compound: vec![],
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                }),
+                alias: Some("ascending".to_owned())
+            }
+        ),
+        (
+            "t AS asset",
+            TableOrSubquery::Table(QualifiedTable {
+                schema_table: SchemaObject {
+                    schema_name: None,
+                    name: "t".to_owned()
+                },
+                alias: Some("asset".to_owned()),
+                indexed: None
+            })
+        ),
+    ]
+);
+
+test_parse!(
+    test_from_clause,
+    Rule::from_clause,
+    FromClause::parse,
+    [
+        (
+            "FROM a, b",
+            FromClause::TableOrQuerys(vec![
+                TableOrSubquery::Table(QualifiedTable {
+                    schema_table: SchemaObject {
+                        schema_name: None,
+                        name: "a".to_owned()
+                    },
+                    alias: None,
+                    indexed: None
+                }),
+                TableOrSubquery::Table(QualifiedTable {
+                    schema_table: SchemaObject {
+                        schema_name: None,
+                        name: "b".to_owned()
+                    },
+                    alias: None,
+                    indexed: None
+                }),
+            ])
+        ),
+        (
+            "FROM a, b JOIN c",
+            FromClause::Join(JoinClause {
+                table_or_subquery: TableOrSubquery::Table(QualifiedTable {
+                    schema_table: SchemaObject {
+                        schema_name: None,
+                        name: "a".to_owned()
+                    },
+                    alias: None,
+                    indexed: None
+                }),
+                joins: vec![
+                    JoinSubClause {
+                        operator: JoinOperator::Comma,
+                        table_or_subquery: TableOrSubquery::Table(QualifiedTable {
+                            schema_table: SchemaObject {
+                                schema_name: None,
+                                name: "b".to_owned()
+                            },
+                            alias: None,
+                            indexed: None
+                        }),
+                        constraint: None
+                    },
+                    JoinSubClause {
+                        operator: JoinOperator::Inner(false),
+                        table_or_subquery: TableOrSubquery::Table(QualifiedTable {
+                            schema_table: SchemaObject {
+                                schema_name: None,
+                                name: "c".to_owned()
+                            },
+                            alias: None,
+                            indexed: None
+                        }),
+                        constraint: None
+                    }
+                ]
+            })
+        ),
+    ]
+);
+
+test_parse!(
+    test_with_clause,
+    Rule::with_clause,
+    WithClause::parse,
+    [
+        (
+            "WITH RECURSIVE cnt(x) AS (VALUES (1))",
+            WithClause {
+                recursive: true,
+                ctes: vec![CommonTableExpr {
+                    name: "cnt".to_owned(),
+                    columns: vec!["x".to_owned()],
+                    materialized: None,
+                    select: Select {
+                        with_clause: None,
+                        core: SelectCore::Values(vec![vec![Expr::Literal(Literal::Integer(
+                            "1".to_owned()
+                        ))]]),
+                        compound: vec![],
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    }
+                }]
+            }
+        ),
+        (
+            "WITH a AS MATERIALIZED (VALUES (1)), b AS NOT MATERIALIZED (VALUES (2))",
+            WithClause {
+                recursive: false,
+                ctes: vec![
+                    CommonTableExpr {
+                        name: "a".to_owned(),
+                        columns: vec![],
+                        materialized: Some(true),
+                        select: Select {
+                            with_clause: None,
+                            core: SelectCore::Values(vec![vec![Expr::Literal(Literal::Integer(
+                                "1".to_owned()
+                            ))]]),
+                            compound: vec![],
+                            order_by: vec![],
+                            limit: None,
+                            offset: None
+                        }
+                    },
+                    CommonTableExpr {
+                        name: "b".to_owned(),
+                        columns: vec![],
+                        materialized: Some(false),
+                        select: Select {
+                            with_clause: None,
+                            core: SelectCore::Values(vec![vec![Expr::Literal(Literal::Integer(
+                                "2".to_owned()
+                            ))]]),
+                            compound: vec![],
+                            order_by: vec![],
+                            limit: None,
+                            offset: None
+                        }
+                    }
+                ]
+            }
+        ),
+        (
+            "WITH recursive_cte AS (VALUES (1))",
+            WithClause {
+                recursive: false,
+                ctes: vec![CommonTableExpr {
+                    name: "recursive_cte".to_owned(),
+                    columns: vec![],
+                    materialized: None,
+                    select: Select {
+                        with_clause: None,
+                        core: SelectCore::Values(vec![vec![Expr::Literal(Literal::Integer(
+                            "1".to_owned()
+                        ))]]),
+                        compound: vec![],
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    }
+                }]
+            }
+        ),
+    ]
+);
+
 test_parse!(
     test_update,
     Rule::update,
@@ -425,6 +704,7 @@ test_parse!(
         (
             "update employee set eage = eage + 1 returning *",
             Update {
+                with_clause: None,
                 conflict: ConflictResolution::Abort,
                 qualified_table: QualifiedTable {
                     schema_table: SchemaObject {
@@ -450,6 +730,7 @@ test_parse!(
         (
             "update OR ROLLBACK employee set eage = eage + 1",
             Update {
+                with_clause: None,
                 conflict: ConflictResolution::Rollback,
                 qualified_table: QualifiedTable {
                     schema_table: SchemaObject {
@@ -483,6 +764,7 @@ test_parse!(
         (
             "delete from employee where eid = 1001",
             Delete {
+                with_clause: None,
                 qualified_table: QualifiedTable {
                     schema_table: SchemaObject {
                         schema_name: None,
@@ -502,6 +784,7 @@ test_parse!(
         (
             "delete from employee returning *",
             Delete {
+                with_clause: None,
                 qualified_table: QualifiedTable {
                     schema_table: SchemaObject {
                         schema_name: None,
@@ -551,6 +834,20 @@ test_parse!(
                 Some("identifier".to_owned())
             )
         ),
+        (
+            "count(*) overdue",
+            ResultColumn::Expr(
+                Expr::Function {
+                    name: "count".to_owned(),
+                    distinct: false,
+                    args: FunctionArgs::Star,
+                    filter: None,
+                    order_by: vec![],
+                    over: None
+                },
+                Some("overdue".to_owned())
+            )
+        ),
     ]
 );
 
@@ -563,7 +860,8 @@ test_parse!(
             "ON CONFLICT (id) DO NOTHING",
             UpsertSubClause {
                 indexed_cols: vec![IndexedColumn {
-                    name: "id".to_owned(),
+                    expr: Expr::QualifiedColumn(None, None, "id".to_owned()),
+                    collation: None,
                     asc: true
                 }],
                 where_clause: None,
@@ -575,11 +873,13 @@ test_parse!(
             UpsertSubClause {
                 indexed_cols: vec![
                     IndexedColumn {
-                        name: "id".to_owned(),
+                        expr: Expr::QualifiedColumn(None, None, "id".to_owned()),
+                        collation: None,
                         asc: false
                     },
                     IndexedColumn {
-                        name: "name".to_owned(),
+                        expr: Expr::QualifiedColumn(None, None, "name".to_owned()),
+                        collation: None,
                         asc: true
                     }
                 ],
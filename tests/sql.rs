@@ -13,6 +13,40 @@ fn test_sql_samples() {
         "DELETE FROM users WHERE id = 1;",
         "CREATE INDEX idx_name ON users (name);",
         "DROP TABLE users;",
+        "SELECT count(*), max(age) FROM users GROUP BY name HAVING count(*) > 1;",
+        "SELECT CASE WHEN age < 18 THEN 'minor' ELSE 'adult' END AS kind FROM users ORDER BY CASE name WHEN 'root' THEN 0 ELSE 1 END;",
+        "CREATE TABLE t (kind INT CHECK (CASE WHEN kind > 0 THEN 1 ELSE 0 END));",
+        "SELECT CAST(total AS REAL) / CAST(count(*) AS REAL) FROM orders;",
+        "SELECT * FROM users WHERE id NOT IN (SELECT user_id FROM banned) AND role IN ('admin', 'owner');",
+        "SELECT id FROM a INTERSECT SELECT id FROM b;",
+        "SELECT name, (SELECT max(total) FROM orders WHERE orders.user_id = users.id) FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id);",
+        "DELETE FROM users WHERE NOT EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id);",
+        "SELECT nullif(a, b), replace(a, 'x', 'y'), like(a, b), glob(a, b) FROM t WHERE a = nullif(b, 0);",
+        "SELECT * FROM files WHERE path LIKE '%\\_tmp%' ESCAPE '\\' AND name NOT GLOB '.*';",
+        "SELECT u.name, o.total FROM users AS u LEFT JOIN (SELECT user_id, sum(total) AS total FROM orders GROUP BY user_id) AS o ON o.user_id = u.id;",
+        "SELECT * FROM (a JOIN b USING (id)) CROSS JOIN json_each(a.tags) AS t;",
+        "UPDATE inventory SET qty = qty - d.n FROM (SELECT item, count(*) AS n FROM orders GROUP BY item) d WHERE inventory.item = d.item;",
+        "WITH RECURSIVE org(id, name, depth) AS (SELECT id, name, 0 FROM employees WHERE manager_id IS NULL UNION ALL SELECT e.id, e.name, org.depth + 1 FROM employees AS e JOIN org ON e.manager_id = org.id) SELECT * FROM org ORDER BY depth;",
+        "WITH stale AS (SELECT id FROM sessions WHERE expires < 100) DELETE FROM sessions WHERE id IN stale;",
+        "WITH totals AS NOT MATERIALIZED (SELECT user_id, sum(total) AS total FROM orders GROUP BY user_id) UPDATE users SET spent = totals.total FROM totals WHERE users.id = totals.user_id;",
+        "WITH src AS (VALUES (1, 'a')) INSERT INTO t (id, name) SELECT * FROM src;",
+        "SELECT name, row_number() OVER (PARTITION BY dept ORDER BY salary DESC) AS rn, lag(salary, 1) OVER w, sum(salary) OVER (w ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) FROM employees WINDOW w AS (ORDER BY hired_at);",
+        "SELECT sum(x) OVER (ORDER BY id ROWS BETWEEN 1 FOLLOWING AND UNBOUNDED FOLLOWING), sum(x) OVER (ORDER BY id GROUPS CURRENT ROW) FROM t;",
+        "SELECT day, avg(total) OVER (ORDER BY day GROUPS BETWEEN 3 PRECEDING AND 3 FOLLOWING EXCLUDE CURRENT ROW) FROM sales;",
+        "UPDATE users SET name = :name, email = @email WHERE id = $id RETURNING *;",
+        "INSERT INTO t (a, b) VALUES (?, ?2), (?3, :d);",
+        "CREATE TABLE tags (name TEXT NOT NULL COLLATE NOCASE UNIQUE, slug TEXT);",
+        "CREATE INDEX idx_tags_name ON tags (name COLLATE NOCASE DESC);",
+        "SELECT name FROM tags WHERE slug = 'x' COLLATE RTRIM ORDER BY name COLLATE NOCASE ASC;",
+        "SELECT * FROM users WHERE email NOTNULL AND deleted_at ISNULL AND phone NOT NULL AND a IS NOT DISTINCT FROM b;",
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, user_id INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE, item_id INTEGER, FOREIGN KEY (item_id) REFERENCES items ON DELETE SET DEFAULT ON UPDATE RESTRICT DEFERRABLE INITIALLY IMMEDIATE);",
+        "CREATE TABLE audit (id INTEGER PRIMARY KEY, payload TEXT, kind TEXT GENERATED ALWAYS AS (json_extract(payload, '$.kind')) VIRTUAL, size INT AS (length(payload)) STORED NOT NULL);",
+        "CREATE TABLE ranges (lo INT, hi INT, tag TEXT, CONSTRAINT ordered CHECK (lo < hi), PRIMARY KEY (lo, tag COLLATE NOCASE), UNIQUE (hi) ON CONFLICT IGNORE);",
+        "CREATE INDEX idx_lower ON users (lower(email), created_at DESC);",
+        "CREATE TABLE events (id INTEGER PRIMARY KEY, created INTEGER DEFAULT (strftime('%s', 'now')), at TEXT DEFAULT CURRENT_TIMESTAMP, day TEXT DEFAULT CURRENT_DATE, delta REAL DEFAULT -1.5, flag INT DEFAULT TRUE);",
+        "CREATE VIRTUAL TABLE IF NOT EXISTS places USING rtree(id, min_x, max_x, min_y, max_y);",
+        "CREATE VIRTUAL TABLE docs USING fts5(title, body, tokenize = 'porter unicode61', prefix = '2 3');",
+        "CREATE TRIGGER tr AFTER INSERT ON t BEGIN UPDATE t SET kind = CASE WHEN kind IS NULL THEN 0 ELSE kind END; END;",
     ];
 
     for sample in samples {
@@ -25,5 +59,360 @@ fn test_sql_samples() {
             "Input '{}' was not fully consumed during parsing",
             sample
         );
+
+        // 确保能够构建 AST
+        if let Err(e) = parse_stmt(sample) {
+            panic!("Failed to build AST for '{}': {}", sample, e);
+        }
+    }
+}
+
+#[test]
+fn test_parse_tcl_stmt() {
+    let cases = [
+        ("BEGIN;", Stmt::Begin(Begin(TransactionMode::Deferred))),
+        (
+            "BEGIN IMMEDIATE TRANSACTION;",
+            Stmt::Begin(Begin(TransactionMode::Immediate)),
+        ),
+        ("COMMIT;", Stmt::Commit(Commit)),
+        ("END TRANSACTION;", Stmt::Commit(Commit)),
+        ("ROLLBACK;", Stmt::Rollback(Rollback(None))),
+        (
+            "ROLLBACK TO SAVEPOINT sp1;",
+            Stmt::Rollback(Rollback(Some("sp1".to_owned()))),
+        ),
+        (
+            "SAVEPOINT sp1;",
+            Stmt::Savepoint(Savepoint("sp1".to_owned())),
+        ),
+        (
+            "RELEASE SAVEPOINT sp1;",
+            Stmt::Release(Release("sp1".to_owned())),
+        ),
+        (
+            "RELEASE savepoint1;",
+            Stmt::Release(Release("savepoint1".to_owned())),
+        ),
+        (
+            "RELEASE savepoint_x;",
+            Stmt::Release(Release("savepoint_x".to_owned())),
+        ),
+        (
+            "ROLLBACK TO savepointx;",
+            Stmt::Rollback(Rollback(Some("savepointx".to_owned()))),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(parse_stmt(input).unwrap(), vec![expected]);
+    }
+}
+
+#[test]
+fn test_parse_pragma() {
+    let pragma = |schema_name: Option<&str>, name: &str, value| {
+        Stmt::Pragma(Pragma {
+            name: SchemaObject {
+                schema_name: schema_name.map(str::to_owned),
+                name: name.to_owned(),
+            },
+            value,
+        })
+    };
+
+    let cases = [
+        ("PRAGMA foreign_keys;", pragma(None, "foreign_keys", None)),
+        (
+            "PRAGMA main.journal_mode = WAL;",
+            pragma(
+                Some("main"),
+                "journal_mode",
+                Some(PragmaValue::Name("wal".to_owned())),
+            ),
+        ),
+        (
+            "PRAGMA foreign_keys = ON;",
+            pragma(
+                None,
+                "foreign_keys",
+                Some(PragmaValue::Name("on".to_owned())),
+            ),
+        ),
+        (
+            "PRAGMA journal_mode = DELETE;",
+            pragma(
+                None,
+                "journal_mode",
+                Some(PragmaValue::Name("delete".to_owned())),
+            ),
+        ),
+        (
+            "PRAGMA user_version = 3;",
+            pragma(
+                None,
+                "user_version",
+                Some(PragmaValue::Number(Literal::Integer("3".to_owned()))),
+            ),
+        ),
+        (
+            "PRAGMA cache_size(-2000);",
+            pragma(
+                None,
+                "cache_size",
+                Some(PragmaValue::Number(Literal::Integer("-2000".to_owned()))),
+            ),
+        ),
+        (
+            "PRAGMA table_info('users');",
+            pragma(
+                None,
+                "table_info",
+                Some(PragmaValue::String("users".to_owned())),
+            ),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(parse_stmt(input).unwrap(), vec![expected]);
+    }
+}
+
+#[test]
+fn test_parse_attach_detach() {
+    let cases = [
+        (
+            "ATTACH DATABASE 'archive.db' AS archive;",
+            Stmt::Attach(Attach {
+                expr: Expr::Literal(Literal::String("archive.db".to_owned())),
+                schema_name: "archive".to_owned(),
+            }),
+        ),
+        (
+            "ATTACH :path AS aux;",
+            Stmt::Attach(Attach {
+                expr: Expr::Param(Param::Named(":path".to_owned())),
+                schema_name: "aux".to_owned(),
+            }),
+        ),
+        (
+            "DETACH DATABASE archive;",
+            Stmt::Detach(Detach("archive".to_owned())),
+        ),
+        ("DETACH aux;", Stmt::Detach(Detach("aux".to_owned()))),
+        (
+            "ATTACH database_file AS x;",
+            Stmt::Attach(Attach {
+                expr: Expr::QualifiedColumn(None, None, "database_file".to_owned()),
+                schema_name: "x".to_owned(),
+            }),
+        ),
+        (
+            "DETACH database_b;",
+            Stmt::Detach(Detach("database_b".to_owned())),
+        ),
+        (
+            "DETACH DATABASE databases;",
+            Stmt::Detach(Detach("databases".to_owned())),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(parse_stmt(input).unwrap(), vec![expected]);
+    }
+
+    let script = "ATTACH 'a.db' AS a;\nINSERT INTO a.t SELECT * FROM main.t;\nDETACH a;\n";
+    assert_eq!(parse_script(script).unwrap().len(), 3);
+}
+
+#[test]
+fn test_parse_maintenance_stmt() {
+    let schema_object = |schema_name: Option<&str>, name: &str| SchemaObject {
+        schema_name: schema_name.map(str::to_owned),
+        name: name.to_owned(),
+    };
+
+    let cases = [
+        (
+            "VACUUM;",
+            Stmt::Vacuum(Vacuum {
+                schema_name: None,
+                into: None,
+            }),
+        ),
+        (
+            "VACUUM main INTO 'backup.db';",
+            Stmt::Vacuum(Vacuum {
+                schema_name: Some("main".to_owned()),
+                into: Some(Expr::Literal(Literal::String("backup.db".to_owned()))),
+            }),
+        ),
+        (
+            "VACUUM INTO ?;",
+            Stmt::Vacuum(Vacuum {
+                schema_name: None,
+                into: Some(Expr::Param(Param::Anonymous)),
+            }),
+        ),
+        ("ANALYZE;", Stmt::Analyze(Analyze(None))),
+        (
+            "ANALYZE main.users;",
+            Stmt::Analyze(Analyze(Some(schema_object(Some("main"), "users")))),
+        ),
+        ("REINDEX;", Stmt::Reindex(Reindex(None))),
+        (
+            "REINDEX nocase;",
+            Stmt::Reindex(Reindex(Some(schema_object(None, "nocase")))),
+        ),
+        (
+            "REINDEX aux.idx_users_email;",
+            Stmt::Reindex(Reindex(Some(schema_object(Some("aux"), "idx_users_email")))),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(parse_stmt(input).unwrap(), vec![expected]);
+    }
+}
+
+#[test]
+fn test_parse_explain() {
+    let stmts = parse_stmt("EXPLAIN QUERY PLAN SELECT * FROM users WHERE id = ?;").unwrap();
+    let [Stmt::Explain { query_plan, stmt }] = stmts.as_slice() else {
+        panic!("expected EXPLAIN statement, got {:?}", stmts);
+    };
+    assert!(query_plan);
+    assert!(matches!(**stmt, Stmt::Select(_)));
+
+    assert_eq!(
+        parse_stmt("explain commit;").unwrap(),
+        vec![Stmt::Explain {
+            query_plan: false,
+            stmt: Box::new(Stmt::Commit(Commit)),
+        }]
+    );
+
+    // 错误信息仍指向被 EXPLAIN 的语句
+    let err = parse_stmt("EXPLAIN SELECT ?0;").unwrap_err();
+    assert_eq!(err.stmt, Some(Rule::select));
+}
+
+#[test]
+fn test_parse_script() {
+    let script = "-- migration\nBEGIN;\nCREATE TABLE t (id INTEGER);\n  INSERT INTO t VALUES (1);\nCOMMIT;\n";
+    let stmts = parse_script(script).unwrap();
+
+    let locations: Vec<_> = stmts
+        .iter()
+        .map(|s| (&script[s.span.clone()], s.line, s.col))
+        .collect();
+    assert_eq!(
+        locations,
+        [
+            ("BEGIN;", 2, 1),
+            ("CREATE TABLE t (id INTEGER);", 3, 1),
+            ("INSERT INTO t VALUES (1);", 4, 3),
+            ("COMMIT;", 5, 1),
+        ]
+    );
+    assert_eq!(stmts[0].stmt, Stmt::Begin(Begin(TransactionMode::Deferred)));
+    assert!(matches!(stmts[1].stmt, Stmt::CreateTable(_)));
+
+    // 空脚本或仅含注释的脚本
+    assert_eq!(parse_script("").unwrap(), vec![]);
+    assert_eq!(parse_script("-- nothing here\n").unwrap(), vec![]);
+
+    // 错误信息指向出错语句所在的行
+    let err = parse_script("BEGIN;\nSELEC 1;\nCOMMIT;").unwrap_err();
+    assert_eq!(err.line_col, (2, 1));
+}
+
+#[test]
+fn test_parse_params() {
+    let params =
+        parse_params("SELECT * FROM t WHERE a = ? AND b = :b AND c = ?5 AND d = ? AND e = :b;")
+            .unwrap();
+    assert_eq!(
+        params,
+        [
+            (1, Param::Anonymous),
+            (2, Param::Named(":b".to_owned())),
+            (5, Param::Numbered(5)),
+            (6, Param::Anonymous),
+        ]
+    );
+
+    // 编号参数与命名参数共享同一位置时保留首次出现的参数
+    let params = parse_params("INSERT INTO t VALUES (@x, ?1, ?);").unwrap();
+    assert_eq!(
+        params,
+        [(1, Param::Named("@x".to_owned())), (2, Param::Anonymous)]
+    );
+
+    assert_eq!(parse_params("SELECT 1;").unwrap(), []);
+    assert!(parse_params("SELECT ?0;").is_err());
+}
+
+#[test]
+fn test_parse_error() {
+    use pest::Parser;
+
+    // 语法错误
+    let err = parse_stmt("SELECT * FROM;").unwrap_err();
+    assert_eq!(err.stmt, None);
+    assert_eq!(err.span, 13..13);
+    assert_eq!(err.line_col, (1, 14));
+    assert!(!err.expected.is_empty());
+    assert!(err.snippet.contains("SELECT * FROM;"));
+
+    // 语法树与 AST 不匹配时返回错误而不是 panic
+    let pair = SqlParser::parse(Rule::select, "SELECT 1")
+        .unwrap()
+        .next()
+        .unwrap();
+    let err = Insert::parse(pair).unwrap_err();
+    assert_eq!(err.found, Some(Rule::select_core1));
+    assert_eq!(err.expected, [Rule::insert_header1, Rule::insert_header2]);
+    assert_eq!(err.span, 0..8);
+    assert!(err.to_string().contains("SELECT 1"));
+
+    let pair = SqlParser::parse(Rule::stmt, "DROP TABLE t;")
+        .unwrap()
+        .next()
+        .unwrap();
+    assert!(Stmt::parse(pair).is_ok());
+}
+
+#[test]
+fn test_parse_error_hides_lookahead() {
+    // 仅用于前瞻的关键字边界规则不出现在错误信息中
+    let inputs = [
+        "ROLLBACK TO;",
+        "DETACH;",
+        "SELECT count(*) OVER;",
+        "SELECT a COLLATE;",
+        "SELECT * FROM t WHERE a NOT LIKE;",
+        "SELECT * FROM t WHERE a LIKE 'x' ESCAPE;",
+        "SELECT * FROM t WHERE a LIKE b c;",
+        "SELECT * FROM t WHERE a NOT x;",
+        "SELECT a IS;",
+    ];
+
+    for input in inputs {
+        let err = parse_stmt(input).unwrap_err();
+        assert!(
+            !err.expected.is_empty(),
+            "'{}' reports no expected rules",
+            input
+        );
+        for rule in &err.expected {
+            assert!(
+                !format!("{:?}", rule).starts_with("kw_"),
+                "'{}' reports lookahead rule {:?}",
+                input,
+                rule
+            );
+        }
+        assert!(!err.snippet.contains("kw_"), "{}", err.snippet);
     }
 }
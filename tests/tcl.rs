@@ -39,6 +39,14 @@ test_parse!(
             "ROLLBACK TRANSACTION TO \"Savepoint2\"",
             Rollback(Some("Savepoint2".to_owned()))
         ),
+        (
+            "ROLLBACK TO savepointx",
+            Rollback(Some("savepointx".to_owned()))
+        ),
+        (
+            "ROLLBACK TO SAVEPOINT savepoint_x",
+            Rollback(Some("savepoint_x".to_owned()))
+        ),
     ]
 );
 
@@ -62,5 +70,10 @@ test_parse!(
     [
         ("RELEASE sp1", Release("sp1".to_owned())),
         ("RELEASE \"MySavepoint\"", Release("MySavepoint".to_owned())),
+        ("RELEASE savepoint_x", Release("savepoint_x".to_owned())),
+        (
+            "RELEASE SAVEPOINT savepointx",
+            Release("savepointx".to_owned())
+        ),
     ]
 );